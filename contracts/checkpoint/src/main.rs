@@ -0,0 +1,107 @@
+#![no_std]
+#![cfg_attr(not(test), no_main)]
+
+#[cfg(test)]
+extern crate alloc;
+
+#[cfg(not(test))]
+use ckb_std::default_alloc;
+#[cfg(not(test))]
+ckb_std::entry!(program_entry);
+#[cfg(not(test))]
+default_alloc!();
+
+// Checkpoint type script. The time cell is consumed on every update, an update
+// may also create checkpoint cells copying the new TimeInfo, which can never
+// be spent and so stay referenceable as cell deps to prove what time it was at
+// a past moment.
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    error::SysError,
+    high_level::{load_cell_data, load_cell_type_hash, load_script},
+};
+use time_oracle_error::CheckpointError as Error;
+
+pub fn program_entry() -> i8 {
+    ckb_std::debug!("This is a checkpoint contract!");
+
+    match verify() {
+        Ok(_) => 0,
+        Err(e) => e as i8,
+    }
+}
+
+fn verify() -> Result<(), Error> {
+    let time_type_hash = load_args()?;
+
+    match load_cell_type_hash(0, Source::GroupInput) {
+        Ok(_) => {
+            debug!("Checkpoint cell can never be spent!");
+            return Err(Error::CheckpointSpent);
+        }
+        Err(SysError::IndexOutOfBound) => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    if find_time_cell(&time_type_hash, Source::Input)?.is_none() {
+        debug!("Checkpoint is not created by a time cell update!");
+        return Err(Error::NotAnUpdate);
+    }
+    let index = find_time_cell(&time_type_hash, Source::Output)?.ok_or_else(|| {
+        debug!("Time cell is missing in outputs!");
+        Error::TimeCellMissing
+    })?;
+    let time_info = load_cell_data(index, Source::Output)?;
+
+    let mut i = 0;
+    loop {
+        let data = match load_cell_data(i, Source::GroupOutput) {
+            Ok(data) => data,
+            Err(SysError::IndexOutOfBound) => break,
+            Err(e) => return Err(e.into()),
+        };
+        if data != time_info {
+            debug!("Checkpoint {} differs from the new time info!", i);
+            return Err(Error::CheckpointMismatch);
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Locating the only cell in source whose type script hash is type_hash.
+fn find_time_cell(type_hash: &[u8; 32], source: Source) -> Result<Option<usize>, Error> {
+    let mut found = None;
+    let mut i = 0;
+    loop {
+        match load_cell_type_hash(i, source) {
+            Ok(Some(hash)) if &hash == type_hash => {
+                if found.is_some() {
+                    debug!("Time cell is found more than once!");
+                    return Err(Error::DuplicatedTimeCell);
+                }
+                found = Some(i);
+            }
+            Ok(_) => {}
+            Err(SysError::IndexOutOfBound) => return Ok(found),
+            Err(e) => return Err(e.into()),
+        }
+        i += 1;
+    }
+}
+
+/// Loading the time type hash from current script args, args must be exactly
+/// 32 byte long.
+fn load_args() -> Result<[u8; 32], Error> {
+    let script = load_script()?;
+    let args = script.as_reader().args();
+    let args = args.raw_data();
+    if args.len() != 32 {
+        debug!("Length of args is incorrect! args len is {}", args.len());
+        return Err(Error::InvalidArgsLength);
+    }
+    let mut time_type_hash = [0; 32];
+    time_type_hash.copy_from_slice(args);
+    Ok(time_type_hash)
+}
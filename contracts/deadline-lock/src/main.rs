@@ -0,0 +1,122 @@
+#![no_std]
+#![cfg_attr(not(test), no_main)]
+
+#[cfg(test)]
+extern crate alloc;
+
+#[cfg(not(test))]
+use ckb_std::default_alloc;
+#[cfg(not(test))]
+ckb_std::entry!(program_entry);
+#[cfg(not(test))]
+default_alloc!();
+
+// Lock spendable only before a deadline attested by the time oracle. The
+// native since can only express "not before", bids, auctions and option
+// expiries need "only before", which requires a fresh time cell in cell_deps
+// showing the current time is below the deadline.
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    error::SysError,
+    high_level::{load_cell_lock_hash, load_script},
+};
+use time_oracle_error::DeadlineLockError as Error;
+use time_oracle_reader::{assert_before, assert_fresh, find_time_cell};
+
+// Maximum age in milliseconds of the time cell relative to the time proved by
+// the transaction, an older oracle could still show a passed deadline ahead.
+const MAX_ORACLE_AGE: u64 = 15 * 60 * 1000;
+
+pub fn program_entry() -> i8 {
+    ckb_std::debug!("This is a deadline lock contract!");
+
+    match verify() {
+        Ok(_) => 0,
+        Err(e) => e as i8,
+    }
+}
+
+/// Args of the deadline lock.
+struct DeadlineArgs {
+    time_type_hash: [u8; 32],
+    /// Deadline in milliseconds, the lock is spendable only before it
+    deadline: u64,
+    /// Lock hash which must also unlock an input, anyone can spend before the
+    /// deadline when absent
+    owner_lock_hash: Option<[u8; 32]>,
+}
+
+fn verify() -> Result<(), Error> {
+    let args = load_args()?;
+
+    let time_info = find_time_cell(&args.time_type_hash).map_err(reader_error)?;
+    assert_fresh(&time_info, MAX_ORACLE_AGE).map_err(reader_error)?;
+    assert_before(&time_info, args.deadline).map_err(reader_error)?;
+
+    if let Some(owner_lock_hash) = args.owner_lock_hash
+        && !has_input_locked_by(&owner_lock_hash)?
+    {
+        debug!("No input is locked by the owner lock!");
+        return Err(Error::OwnerMissing);
+    }
+    Ok(())
+}
+
+/// Mapping failures of the reader to the exit codes of the deadline lock, an
+/// MMR or time proof is never read so their failures only mean invalid time
+/// cell data.
+fn reader_error(err: time_oracle_reader::Error) -> Error {
+    match err {
+        time_oracle_reader::Error::Syscall(err) => err.into(),
+        time_oracle_reader::Error::TimeCellMissing => Error::TimeCellMissing,
+        time_oracle_reader::Error::DuplicatedTimeCell => Error::DuplicatedTimeCell,
+        time_oracle_reader::Error::InvalidTimeInfo
+        | time_oracle_reader::Error::InvalidTimeMmr
+        | time_oracle_reader::Error::TimeMmrMissing
+        | time_oracle_reader::Error::InvalidTimeProof => Error::InvalidTimeInfo,
+        time_oracle_reader::Error::MissingTimeProof => Error::MissingTimeProof,
+        time_oracle_reader::Error::StaleTimeInfo => Error::StaleTimeInfo,
+        time_oracle_reader::Error::DeadlinePassed => Error::DeadlinePassed,
+    }
+}
+
+fn has_input_locked_by(lock_hash: &[u8; 32]) -> Result<bool, Error> {
+    let mut i = 0;
+    loop {
+        match load_cell_lock_hash(i, Source::Input) {
+            Ok(hash) if &hash == lock_hash => return Ok(true),
+            Ok(_) => {}
+            Err(SysError::IndexOutOfBound) => return Ok(false),
+            Err(e) => return Err(e.into()),
+        }
+        i += 1;
+    }
+}
+
+/// Loading the time type hash, the deadline as u64 little endian and the
+/// optional owner lock hash from current script args, args must be 40 or 72
+/// byte long.
+fn load_args() -> Result<DeadlineArgs, Error> {
+    let script = load_script()?;
+    let args = script.as_reader().args();
+    let args = args.raw_data();
+    if args.len() != 40 && args.len() != 72 {
+        debug!("Length of args is incorrect! args len is {}", args.len());
+        return Err(Error::InvalidArgsLength);
+    }
+    let mut time_type_hash = [0; 32];
+    time_type_hash.copy_from_slice(&args[..32]);
+    let mut deadline = [0; 8];
+    deadline.copy_from_slice(&args[32..40]);
+    let owner_lock_hash = args.get(40..).filter(|hash| !hash.is_empty()).map(|hash| {
+        let mut owner_lock_hash = [0; 32];
+        owner_lock_hash.copy_from_slice(hash);
+        owner_lock_hash
+    });
+    Ok(DeadlineArgs {
+        time_type_hash,
+        deadline: u64::from_le_bytes(deadline),
+        owner_lock_hash,
+    })
+}
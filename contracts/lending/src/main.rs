@@ -0,0 +1,243 @@
+#![no_std]
+#![cfg_attr(not(test), no_main)]
+
+#[cfg(test)]
+extern crate alloc;
+
+#[cfg(not(test))]
+use ckb_std::default_alloc;
+#[cfg(not(test))]
+ckb_std::entry!(program_entry);
+#[cfg(not(test))]
+default_alloc!();
+
+// Example lending contract reading the time oracle. Cells typed by this script
+// are either deposits, whose data is the deposit timestamp, or pool cells with
+// empty data funding the interest. A deposit is withdrawn in a single
+// transaction: the interest up to the time of the time cell in cell_deps is
+// paid from the pool to the lock of the deposit, no withdrawal order needed.
+// Interest accrues from the deposit timestamp, or from the block committing the
+// deposit when that is later: the oracle is only checked against the time the
+// depositor proves, a deposit proving an old header cannot earn interest from
+// before it was committed.
+use alloc::vec::Vec;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::Unpack,
+    debug,
+    error::SysError,
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_header,
+        load_script, load_script_hash,
+    },
+};
+use time_oracle_error::LendingError as Error;
+use time_oracle_reader::{TimeInfo, assert_fresh, find_time_cell};
+
+// Maximum age in milliseconds of the oracle when a deposit is created, counted
+// from the latest time proved by the deposit transaction.
+const MAX_ORACLE_AGE: u64 = 15 * 60 * 1000;
+const YEAR: u64 = 365 * 24 * 60 * 60 * 1000;
+const BASIS_POINTS: u64 = 10_000;
+
+pub fn program_entry() -> i8 {
+    ckb_std::debug!("This is a lending contract!");
+
+    match verify() {
+        Ok(_) => 0,
+        Err(e) => e as i8,
+    }
+}
+
+/// A cell typed by the lending script.
+enum LendingCell {
+    Pool,
+    Deposit { timestamp: u64 },
+}
+
+fn load_lending_cell(index: usize, source: Source) -> Result<LendingCell, Error> {
+    let data = load_cell_data(index, source)?;
+    match data.len() {
+        0 => Ok(LendingCell::Pool),
+        8 => {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&data);
+            Ok(LendingCell::Deposit {
+                timestamp: u64::from_le_bytes(buf),
+            })
+        }
+        len => {
+            debug!(
+                "Length of lending cell data is incorrect! data len is {}",
+                len
+            );
+            Err(Error::InvalidDepositData)
+        }
+    }
+}
+
+fn verify() -> Result<(), Error> {
+    let (time_type_hash, rate) = load_args()?;
+    // the oracle is only loaded once a deposit is created or withdrawn
+    let mut time_info: Option<TimeInfo> = None;
+
+    let mut pool_inputs = 0u64;
+    let mut interest_paid = 0u64;
+    // capacity owed to each lock hash by the withdrawn deposits
+    let mut owed: Vec<([u8; 32], u64)> = Vec::new();
+    let mut i = 0;
+    loop {
+        let cell = match load_lending_cell(i, Source::GroupInput) {
+            Ok(cell) => cell,
+            Err(Error::IndexOutOfBound) => break,
+            Err(e) => return Err(e),
+        };
+        let capacity = load_cell_capacity(i, Source::GroupInput)?;
+        match cell {
+            LendingCell::Pool => pool_inputs = pool_inputs.saturating_add(capacity),
+            LendingCell::Deposit { timestamp } => {
+                let start = timestamp.max(load_committed_timestamp(i)?);
+                let now = u64::from(load_oracle(&mut time_info, &time_type_hash)?.timestamp());
+                let interest = calc_interest(capacity, rate, now.saturating_sub(start));
+                interest_paid = interest_paid.saturating_add(interest);
+                let lock_hash = load_cell_lock_hash(i, Source::GroupInput)?;
+                let amount = capacity.saturating_add(interest);
+                match owed.iter_mut().find(|(hash, _)| hash == &lock_hash) {
+                    Some((_, owed)) => *owed = owed.saturating_add(amount),
+                    None => owed.push((lock_hash, amount)),
+                }
+            }
+        }
+        i += 1;
+    }
+
+    let mut pool_outputs = 0u64;
+    let mut i = 0;
+    loop {
+        let cell = match load_lending_cell(i, Source::GroupOutput) {
+            Ok(cell) => cell,
+            Err(Error::IndexOutOfBound) => break,
+            Err(e) => return Err(e),
+        };
+        match cell {
+            LendingCell::Pool => {
+                pool_outputs =
+                    pool_outputs.saturating_add(load_cell_capacity(i, Source::GroupOutput)?)
+            }
+            LendingCell::Deposit { timestamp } => {
+                let oracle = load_oracle(&mut time_info, &time_type_hash)?;
+                assert_fresh(oracle, MAX_ORACLE_AGE).map_err(reader_error)?;
+                if timestamp != u64::from(oracle.timestamp()) {
+                    debug!(
+                        "Deposit timestamp {} is not the oracle timestamp!",
+                        timestamp
+                    );
+                    return Err(Error::InvalidDepositTime);
+                }
+            }
+        }
+        i += 1;
+    }
+
+    if pool_outputs.saturating_add(interest_paid) < pool_inputs {
+        debug!(
+            "Pool is overdrawn! inputs: {}, outputs: {}, interest: {}",
+            pool_inputs, pool_outputs, interest_paid
+        );
+        return Err(Error::PoolOverdrawn);
+    }
+    for (lock_hash, amount) in owed {
+        let received = sum_paid_to(&lock_hash)?;
+        if received < amount {
+            debug!("Deposit is withdrawn with {} of {}!", received, amount);
+            return Err(Error::InterestNotPaid);
+        }
+    }
+    Ok(())
+}
+
+/// Loading the time cell from cell deps on first use.
+fn load_oracle<'a>(
+    time_info: &'a mut Option<TimeInfo>,
+    time_type_hash: &[u8; 32],
+) -> Result<&'a TimeInfo, Error> {
+    if time_info.is_none() {
+        *time_info = Some(find_time_cell(time_type_hash).map_err(reader_error)?);
+    }
+    Ok(time_info.as_ref().expect("time info"))
+}
+
+/// Timestamp of the block committing the deposit input at index, its header
+/// must be in header_deps.
+fn load_committed_timestamp(index: usize) -> Result<u64, Error> {
+    match load_header(index, Source::GroupInput) {
+        Ok(header) => Ok(header.raw().timestamp().unpack()),
+        Err(SysError::ItemMissing) => {
+            debug!("Header deps miss the block committing deposit {}!", index);
+            Err(Error::DepositHeaderMissing)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Mapping failures of the reader to the exit codes of lending, an MMR or time
+/// proof is never read so their failures only mean invalid time cell data.
+fn reader_error(err: time_oracle_reader::Error) -> Error {
+    match err {
+        time_oracle_reader::Error::Syscall(err) => err.into(),
+        time_oracle_reader::Error::TimeCellMissing => Error::TimeCellMissing,
+        time_oracle_reader::Error::DuplicatedTimeCell => Error::DuplicatedTimeCell,
+        time_oracle_reader::Error::InvalidTimeInfo
+        | time_oracle_reader::Error::InvalidTimeMmr
+        | time_oracle_reader::Error::TimeMmrMissing
+        | time_oracle_reader::Error::InvalidTimeProof => Error::InvalidTimeInfo,
+        time_oracle_reader::Error::MissingTimeProof => Error::MissingTimeProof,
+        time_oracle_reader::Error::StaleTimeInfo | time_oracle_reader::Error::DeadlinePassed => {
+            Error::StaleTimeInfo
+        }
+    }
+}
+
+/// Simple interest on capacity at rate basis points per year, rounded down.
+fn calc_interest(capacity: u64, rate: u64, elapsed: u64) -> u64 {
+    let interest =
+        capacity as u128 * rate as u128 * elapsed as u128 / (BASIS_POINTS as u128 * YEAR as u128);
+    interest.min(u64::MAX as u128) as u64
+}
+
+/// Capacity of the outputs locked by lock_hash outside the lending cells, a
+/// deposit cannot be paid out to a new deposit or pool cell.
+fn sum_paid_to(lock_hash: &[u8; 32]) -> Result<u64, Error> {
+    let script_hash = load_script_hash()?;
+    let mut sum = 0u64;
+    let mut i = 0;
+    loop {
+        let output_lock_hash = match load_cell_lock_hash(i, Source::Output) {
+            Ok(hash) => hash,
+            Err(SysError::IndexOutOfBound) => return Ok(sum),
+            Err(e) => return Err(e.into()),
+        };
+        let type_hash = load_cell_type_hash(i, Source::Output)?;
+        if &output_lock_hash == lock_hash && type_hash != Some(script_hash) {
+            sum = sum.saturating_add(load_cell_capacity(i, Source::Output)?);
+        }
+        i += 1;
+    }
+}
+
+/// Loading the time type hash and the yearly interest rate in basis points
+/// from current script args, args must be exactly 40 byte long.
+fn load_args() -> Result<([u8; 32], u64), Error> {
+    let script = load_script()?;
+    let args = script.as_reader().args();
+    let args = args.raw_data();
+    if args.len() != 40 {
+        debug!("Length of args is incorrect! args len is {}", args.len());
+        return Err(Error::ArgsLengthNotEnough);
+    }
+    let mut time_type_hash = [0; 32];
+    time_type_hash.copy_from_slice(&args[..32]);
+    let mut rate = [0; 8];
+    rate.copy_from_slice(&args[32..]);
+    Ok((time_type_hash, u64::from_le_bytes(rate)))
+}
@@ -0,0 +1,114 @@
+#![no_std]
+#![cfg_attr(not(test), no_main)]
+
+#[cfg(test)]
+extern crate alloc;
+
+#[cfg(not(test))]
+use ckb_std::default_alloc;
+#[cfg(not(test))]
+ckb_std::entry!(program_entry);
+#[cfg(not(test))]
+default_alloc!();
+
+// Import CKB syscalls and structures
+// https://docs.rs/ckb-std/
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::packed::Script,
+    debug,
+    error::SysError,
+    high_level::{load_cell_lock_hash, load_cell_type, load_cell_type_hash, load_script},
+};
+use molecule::prelude::Entity;
+use time_oracle_error::TimeLockError as Error;
+use time_oracle_reader::load_time_info;
+
+pub fn program_entry() -> i8 {
+    ckb_std::debug!("This is a time lock contract!");
+
+    match verify() {
+        Ok(_) => 0,
+        Err(e) => e as i8,
+    }
+}
+
+/// Time lock只能用于更新Time Cell
+/// Every input locked by this script must be a time cell, and the transaction
+/// must recreate it with the same type and lock and a valid TimeInfo.
+fn verify() -> Result<(), Error> {
+    let (code_hash, hash_type) = load_time_type_from_script_args()?;
+    let lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+
+    let mut i = 0;
+    loop {
+        let time_type = match load_cell_type(i, Source::GroupInput) {
+            Ok(Some(script)) => script,
+            Ok(None) => {
+                debug!("Input {} locked by time lock has no type!", i);
+                return Err(Error::TimeTypeMissing);
+            }
+            Err(SysError::IndexOutOfBound) => break,
+            Err(e) => return Err(e.into()),
+        };
+        if !is_time_type(&time_type, &code_hash, hash_type) {
+            debug!("Input {} locked by time lock is not a time cell!", i);
+            return Err(Error::InvalidTimeType);
+        }
+
+        let type_hash =
+            load_cell_type_hash(i, Source::GroupInput)?.ok_or(Error::TimeTypeMissing)?;
+        let index = locate_time_output_index(&type_hash, &lock_hash)?;
+        load_time_info(index, Source::Output).map_err(reader_error)?;
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Every failure of the reader other than a syscall means the output data is
+/// not a valid TimeInfo.
+fn reader_error(err: time_oracle_reader::Error) -> Error {
+    match err {
+        time_oracle_reader::Error::Syscall(err) => err.into(),
+        _ => Error::InvalidTimeInfo,
+    }
+}
+
+fn is_time_type(script: &Script, code_hash: &[u8; 32], hash_type: u8) -> bool {
+    script.code_hash().as_slice() == code_hash && u8::from(script.hash_type()) == hash_type
+}
+
+fn locate_time_output_index(type_hash: &[u8; 32], lock_hash: &[u8; 32]) -> Result<usize, Error> {
+    let mut i = 0;
+    loop {
+        match load_cell_type_hash(i, Source::Output) {
+            Ok(Some(hash)) if &hash == type_hash => {
+                if &load_cell_lock_hash(i, Source::Output)? == lock_hash {
+                    return Ok(i);
+                }
+            }
+            Ok(_) => {}
+            Err(SysError::IndexOutOfBound) => {
+                debug!("Time output is missing!");
+                return Err(Error::TimeOutputMissing);
+            }
+            Err(e) => return Err(e.into()),
+        }
+        i += 1;
+    }
+}
+
+/// Loading the time type code hash and hash type from current script args, args
+/// must be exactly 33 byte long.
+pub fn load_time_type_from_script_args() -> Result<([u8; 32], u8), Error> {
+    let script = load_script()?;
+    let args = script.as_reader().args();
+    let args = args.raw_data();
+    if args.len() != 33 {
+        debug!("Length of args is incorrect! args len is {}", args.len());
+        return Err(Error::ArgsLengthNotEnough);
+    }
+    let mut code_hash = [0; 32];
+    code_hash.copy_from_slice(&args[..32]);
+    Ok((code_hash, args[32]))
+}
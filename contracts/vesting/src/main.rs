@@ -0,0 +1,184 @@
+#![no_std]
+#![cfg_attr(not(test), no_main)]
+
+#[cfg(test)]
+extern crate alloc;
+
+#[cfg(not(test))]
+use ckb_std::default_alloc;
+#[cfg(not(test))]
+ckb_std::entry!(program_entry);
+#[cfg(not(test))]
+default_alloc!();
+
+// Example vesting contract reading the time oracle. The data of a vesting cell
+// is its schedule, the capacity vests linearly between start and end once the
+// cliff is reached. Withdrawals take capacity out of the cell as long as the
+// unvested part stays in it, the oracle timestamp replaces since epochs, which
+// are coarse and awkward for calendar schedules. The oracle never runs ahead of
+// the chain, a stale time cell only releases less, so no freshness is required.
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    error::SysError,
+    high_level::{load_cell_capacity, load_cell_data, load_cell_lock_hash, load_script},
+};
+use time_oracle_error::VestingError as Error;
+use time_oracle_reader::find_time_cell;
+
+pub fn program_entry() -> i8 {
+    ckb_std::debug!("This is a vesting contract!");
+
+    match verify() {
+        Ok(_) => 0,
+        Err(e) => e as i8,
+    }
+}
+
+/// Vesting schedule stored in cell data as four u64 little endian, timestamps
+/// in milliseconds.
+#[derive(PartialEq, Eq)]
+struct Schedule {
+    start: u64,
+    cliff: u64,
+    end: u64,
+    total: u64,
+}
+
+impl Schedule {
+    /// Capacity vested at now, nothing before the cliff and total from end on.
+    fn vested(&self, now: u64) -> u64 {
+        if now < self.cliff {
+            0
+        } else if now >= self.end {
+            self.total
+        } else {
+            let vested =
+                self.total as u128 * (now - self.start) as u128 / (self.end - self.start) as u128;
+            vested as u64
+        }
+    }
+}
+
+fn load_schedule(index: usize, source: Source) -> Result<Schedule, Error> {
+    let data = load_cell_data(index, source)?;
+    if data.len() != 32 {
+        debug!(
+            "Length of schedule is incorrect! data len is {}",
+            data.len()
+        );
+        return Err(Error::InvalidSchedule);
+    }
+    let field = |i: usize| {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&data[i * 8..(i + 1) * 8]);
+        u64::from_le_bytes(buf)
+    };
+    let schedule = Schedule {
+        start: field(0),
+        cliff: field(1),
+        end: field(2),
+        total: field(3),
+    };
+    if schedule.start > schedule.cliff || schedule.cliff > schedule.end {
+        debug!("Schedule must satisfy start <= cliff <= end!");
+        return Err(Error::InvalidSchedule);
+    }
+    if schedule.start == schedule.end {
+        debug!("Schedule must end after it starts!");
+        return Err(Error::InvalidSchedule);
+    }
+    Ok(schedule)
+}
+
+/// Returning whether source has a vesting cell, at most one is allowed.
+fn has_single_cell(source: Source) -> Result<bool, Error> {
+    match load_cell_capacity(1, source) {
+        Ok(_) => {
+            debug!("More than one vesting cell!");
+            return Err(Error::MultipleVestingCells);
+        }
+        Err(SysError::IndexOutOfBound) => {}
+        Err(e) => return Err(e.into()),
+    }
+    match load_cell_capacity(0, source) {
+        Ok(_) => Ok(true),
+        Err(SysError::IndexOutOfBound) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn verify() -> Result<(), Error> {
+    let time_type_hash = load_args()?;
+    let has_input = has_single_cell(Source::GroupInput)?;
+    let has_output = has_single_cell(Source::GroupOutput)?;
+
+    if !has_input {
+        // creation, the cell must hold the whole schedule
+        let schedule = load_schedule(0, Source::GroupOutput)?;
+        if load_cell_capacity(0, Source::GroupOutput)? < schedule.total {
+            debug!("Vesting cell holds less than its total!");
+            return Err(Error::InvalidSchedule);
+        }
+        return Ok(());
+    }
+
+    let schedule = load_schedule(0, Source::GroupInput)?;
+    let now = u64::from(
+        find_time_cell(&time_type_hash)
+            .map_err(reader_error)?
+            .timestamp(),
+    );
+    let unvested = schedule.total - schedule.vested(now);
+
+    let remaining = if has_output {
+        if load_schedule(0, Source::GroupOutput)? != schedule {
+            debug!("Schedule of the vesting cell is changed!");
+            return Err(Error::ScheduleChanged);
+        }
+        if load_cell_lock_hash(0, Source::GroupOutput)?
+            != load_cell_lock_hash(0, Source::GroupInput)?
+        {
+            debug!("Lock of the vesting cell is changed!");
+            return Err(Error::LockChanged);
+        }
+        load_cell_capacity(0, Source::GroupOutput)?
+    } else {
+        0
+    };
+    if remaining < unvested {
+        debug!(
+            "Unvested capacity is withdrawn! remaining: {}, unvested: {}",
+            remaining, unvested
+        );
+        return Err(Error::UnvestedWithdrawn);
+    }
+    Ok(())
+}
+
+/// Mapping failures of the reader to the exit codes of vesting, the remaining
+/// errors mean invalid time cell data or come from freshness checks this script
+/// skips.
+fn reader_error(err: time_oracle_reader::Error) -> Error {
+    match err {
+        time_oracle_reader::Error::Syscall(err) => err.into(),
+        time_oracle_reader::Error::TimeCellMissing => Error::TimeCellMissing,
+        time_oracle_reader::Error::DuplicatedTimeCell => Error::DuplicatedTimeCell,
+        _ => Error::InvalidTimeInfo,
+    }
+}
+
+/// Loading the time type hash from current script args, args must be exactly
+/// 32 byte long.
+fn load_args() -> Result<[u8; 32], Error> {
+    let script = load_script()?;
+    let args = script.as_reader().args();
+    let args = args.raw_data();
+    if args.len() != 32 {
+        debug!("Length of args is incorrect! args len is {}", args.len());
+        return Err(Error::InvalidArgsLength);
+    }
+    let mut time_type_hash = [0; 32];
+    time_type_hash.copy_from_slice(args);
+    Ok(time_type_hash)
+}
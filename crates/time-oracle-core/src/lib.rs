@@ -0,0 +1,604 @@
+#![no_std]
+
+//! Validation rules of the time type script.
+//!
+//! All rules read the transaction through [`TxView`], the time contract runs
+//! them with [`OnChainTx`] while native unit tests use an in-memory
+//! transaction from the `mock` module.
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+mod tx_view;
+
+#[cfg(test)]
+mod tests;
+
+pub use time_oracle_error::Error;
+pub use tx_view::{OnChainTx, TxView};
+
+use alloc::vec::Vec;
+use ckb_hash::new_blake2b;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::Unpack,
+    debug,
+    error::SysError,
+    since::{LockValue, Since},
+};
+use molecule::prelude::{Entity, Reader};
+use time_oracle_reader::{
+    TimeInfo, TimeMmr, append_time_entry, parse_time_info, parse_time_mmr, time_entry,
+};
+use time_oracle_types::TimeArgsReader;
+
+// Maximum distance in milliseconds between the claimed timestamp and the since
+// of the time cell input. The median time of the past 37 blocks used to check
+// since lags behind the tip by a few minutes.
+pub const SINCE_TOLERANCE: u64 = 10 * 60 * 1000;
+// Default maximum distance in milliseconds the claimed timestamp may be after
+// the timestamp of the header dep it refers to.
+pub const HEADER_DEP_WINDOW: u64 = 10 * 60 * 1000;
+// Maximum size of the TimeMmr following TimeInfo in the time cell data, one
+// peak per bit of the u64 leaf count.
+pub const MAX_TIME_MMR_SIZE: usize = 24 + 64 * 32;
+// Versions of the args extension following type_id and always_success hash.
+// Version 1 carries the header dep window followed by the minimum update
+// interval, version 2 carries the same fields followed by the minimum fee,
+// version 3 carries a molecule TimeArgs table. Legacy args end after the
+// always_success hash.
+pub const ARGS_VERSION_UPDATE_INTERVAL: u8 = 1;
+pub const ARGS_VERSION_UPDATE_INTERVAL_AND_FEE: u8 = 2;
+pub const ARGS_VERSION_TIME_ARGS: u8 = 3;
+
+/// Parameters carried by the args extension, legacy args have none of them.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ArgsExtension {
+    /// Maximum distance in milliseconds between the claimed timestamp and its
+    /// header dep, zero for HEADER_DEP_WINDOW.
+    pub header_dep_window: u64,
+    /// Minimum interval in milliseconds between two updates.
+    pub min_update_interval: u64,
+    /// Minimum capacity in shannons an update transaction must burn as fee.
+    pub min_fee: u64,
+    /// Lock hashes allowed to update the time cell, anyone may update when
+    /// empty.
+    pub keepers: Vec<[u8; 32]>,
+    /// Position of the time cell among the shards of the oracle.
+    pub shard_index: u8,
+    /// Number of shards, zero for an oracle with a single time cell.
+    pub shard_count: u8,
+}
+
+impl ArgsExtension {
+    /// Window the claimed timestamp must fall in after its header dep.
+    pub fn header_dep_window(&self) -> u64 {
+        if self.header_dep_window > 0 {
+            self.header_dep_window
+        } else {
+            HEADER_DEP_WINDOW
+        }
+    }
+}
+
+/// Running all rules of the time type script against tx.
+pub fn verify<T: TxView>(tx: &T) -> Result<(), Error> {
+    let type_id = load_type_id_from_script_args(tx, 0)?;
+    let extension = load_args_extension_from_script_args(tx, 64)?;
+    validate_type_id(tx, type_id, extension.shard_index)?;
+
+    let always_success = load_always_success_from_script_args(tx, 32)?;
+    let lock_hash = tx.load_cell_lock_hash(0, Source::GroupOutput)?;
+    if lock_hash != always_success {
+        return Err(Error::InvalidTypeIDLock);
+    }
+
+    let time_info = load_time_info(tx, 0, Source::GroupOutput)?;
+    let time_mmr = load_time_mmr(tx, 0, Source::GroupOutput)?;
+    if has_type_id_cell(tx, 0, Source::GroupInput)? {
+        validate_time_update(tx, &time_info)?;
+        validate_time_mmr(tx, &time_info, time_mmr.as_ref())?;
+        validate_since_lower_bound(tx, &time_info)?;
+
+        validate_header_dep(tx, &time_info, extension.header_dep_window())?;
+        if extension.min_update_interval > 0 {
+            validate_update_interval(tx, extension.min_update_interval)?;
+        }
+        if extension.min_fee > 0 {
+            validate_fee(tx, extension.min_fee)?;
+        }
+        if !extension.keepers.is_empty() {
+            validate_keeper(tx, &extension.keepers)?;
+        }
+    } else {
+        validate_created_time_mmr(time_mmr.as_ref())?;
+    }
+    Ok(())
+}
+
+fn has_type_id_cell<T: TxView>(tx: &T, index: usize, source: Source) -> Result<bool, Error> {
+    // just confirm cell presence, no data needed
+    match tx.load_cell_capacity(index, source) {
+        Ok(_) => Ok(true),
+        Err(SysError::IndexOutOfBound) => Ok(false),
+        Err(e) => {
+            debug!("load cell err: {:?}", e);
+            Err(e.into())
+        }
+    }
+}
+
+fn locate_first_type_id_output_index<T: TxView>(tx: &T) -> Result<usize, Error> {
+    let current_script_hash = tx.load_script_hash()?;
+
+    let mut i = 0;
+    loop {
+        let type_hash = tx.load_cell_type_hash(i, Source::Output)?;
+
+        if type_hash == Some(current_script_hash) {
+            break;
+        }
+        i += 1
+    }
+    Ok(i)
+}
+
+/// Time Cell可以被创建、可以被更新、但是不可以被销毁，更新时capacity不可以减少
+/// Given a 32-byte type id, this function validates if
+/// current transaction confronts to the type ID rules. Shards of an oracle
+/// share type_id and are created at consecutive outputs, so shard_index is
+/// subtracted from the output index before calculating the type id, and each
+/// shard is unique on its own as its args differ from the other shards.
+pub fn validate_type_id<T: TxView>(
+    tx: &T,
+    type_id: [u8; 32],
+    shard_index: u8,
+) -> Result<(), Error> {
+    if has_type_id_cell(tx, 1, Source::GroupInput)? {
+        debug!("There can only be at most one input time cell!");
+        return Err(Error::MultipleTimeInputs);
+    }
+
+    // the script only runs when a time cell is in the inputs or the outputs,
+    // so no output means the input time cell is destroyed
+    if !has_type_id_cell(tx, 0, Source::GroupOutput)? {
+        debug!("Time cell can not be destroyed!");
+        return Err(Error::TimeCellDestroyed);
+    }
+    if has_type_id_cell(tx, 1, Source::GroupOutput)? {
+        debug!("There can only one output time cell!");
+        return Err(Error::MultipleTimeOutputs);
+    }
+
+    if !has_type_id_cell(tx, 0, Source::GroupInput)? {
+        // We are creating a new type ID cell here. Additional checkings are needed to ensure the type ID is legit.
+        let index = locate_first_type_id_output_index(tx)?;
+        let Some(first_shard_index) = index.checked_sub(shard_index as usize) else {
+            debug!("Shard {} is created before the first shard!", shard_index);
+            return Err(Error::InvalidShardPosition);
+        };
+
+        let input = tx.load_input(0, Source::Input)?;
+        let ret = calc_type_id(input.as_slice(), first_shard_index as u64);
+
+        debug!("ret: {:?}, type_id: {:?}", ret, type_id);
+
+        if ret != type_id {
+            debug!("Invalid type ID!");
+            return Err(Error::TypeIDNotMatch);
+        }
+    } else {
+        // We are updating the time cell, which is locked by always_success, so
+        // the updater must not drain its capacity.
+        let input_capacity = tx.load_cell_capacity(0, Source::GroupInput)?;
+        let output_capacity = tx.load_cell_capacity(0, Source::GroupOutput)?;
+        if output_capacity < input_capacity {
+            debug!(
+                "Capacity of time cell decreased! input: {}, output: {}",
+                input_capacity, output_capacity
+            );
+            return Err(Error::CapacityDecreased);
+        }
+    }
+    Ok(())
+}
+
+/// The type ID is calculated as the blake2b (with CKB's personalization) of
+/// the first CellInput in current transaction, and the created output cell
+/// index(in 64-bit little endian unsigned integer).
+pub fn calc_type_id(first_input: &[u8], output_index: u64) -> [u8; 32] {
+    let mut hasher = new_blake2b();
+    hasher.update(first_input);
+    hasher.update(&output_index.to_le_bytes());
+    let mut ret = [0; 32];
+    hasher.finalize(&mut ret);
+    ret
+}
+
+/// Loading type ID from current script args, type_id must be at least 32 byte
+/// long.
+pub fn load_type_id_from_script_args<T: TxView>(tx: &T, offset: usize) -> Result<[u8; 32], Error> {
+    let script = tx.load_script()?;
+    let args = script.as_reader().args();
+    if offset + 32 > args.raw_data().len() {
+        debug!("Length of type id is incorrect!");
+        return Err(Error::ArgsLengthNotEnough);
+    }
+    let mut ret = [0; 32];
+    ret.copy_from_slice(&args.raw_data()[offset..offset + 32]);
+    Ok(ret)
+}
+
+/// Loading always_success from current script args, always_success must be at least 32 byte
+/// long.
+pub fn load_always_success_from_script_args<T: TxView>(
+    tx: &T,
+    offset: usize,
+) -> Result<[u8; 32], Error> {
+    let script = tx.load_script()?;
+    let args = script.as_reader().args();
+    if offset + 32 > args.raw_data().len() {
+        debug!(
+            "Length of always success is incorrect! args len is {}",
+            args.raw_data().len()
+        );
+        return Err(Error::ArgsLengthNotEnough);
+    }
+    let mut ret = [0; 32];
+    ret.copy_from_slice(&args.raw_data()[offset..offset + 32]);
+    Ok(ret)
+}
+
+/// Loading the TimeInfo payload from cell data, the data must start with one
+/// molecule encoded TimeInfo of the supported version. What follows is checked
+/// by load_time_mmr.
+pub fn load_time_info<T: TxView>(tx: &T, index: usize, source: Source) -> Result<TimeInfo, Error> {
+    let mut buf = [0u8; TimeInfo::TOTAL_SIZE];
+    match tx.load_cell_data(&mut buf, 0, index, source) {
+        Ok(len) if len == TimeInfo::TOTAL_SIZE => {}
+        Err(SysError::LengthNotEnough(len)) if len > TimeInfo::TOTAL_SIZE => {}
+        Ok(len) | Err(SysError::LengthNotEnough(len)) => {
+            debug!("Length of time info is incorrect! data len is {}", len);
+            return Err(Error::InvalidTimeInfo);
+        }
+        Err(e) => return Err(e.into()),
+    }
+    parse_time_info(&buf).map_err(|_| Error::InvalidTimeInfo)
+}
+
+/// Loading the TimeMmr following TimeInfo in cell data, None when the data is
+/// only a TimeInfo.
+pub fn load_time_mmr<T: TxView>(
+    tx: &T,
+    index: usize,
+    source: Source,
+) -> Result<Option<TimeMmr>, Error> {
+    let mut buf = [0u8; MAX_TIME_MMR_SIZE];
+    match tx.load_cell_data(&mut buf, TimeInfo::TOTAL_SIZE, index, source) {
+        Ok(0) => Ok(None),
+        Ok(len) => parse_time_mmr(&buf[..len])
+            .map(Some)
+            .map_err(|_| Error::InvalidTimeMmr),
+        Err(SysError::LengthNotEnough(len)) => {
+            debug!("Time MMR is too long! len is {}", len);
+            Err(Error::InvalidTimeMmr)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// A time cell storing the MMR of its past values must keep it, and every
+/// update appends exactly the TimeEntry of the new TimeInfo. A time cell
+/// without MMR cannot start one later, the MMR would miss earlier values.
+/// The first update of a legacy time cell with empty data is checked like a
+/// creation.
+pub fn validate_time_mmr<T: TxView>(
+    tx: &T,
+    new_info: &TimeInfo,
+    new_mmr: Option<&TimeMmr>,
+) -> Result<(), Error> {
+    if is_legacy_time_cell(tx)? {
+        return validate_created_time_mmr(new_mmr);
+    }
+    let old_mmr = load_time_mmr(tx, 0, Source::GroupInput)?;
+    match (old_mmr, new_mmr) {
+        (None, None) => Ok(()),
+        (Some(old_mmr), Some(new_mmr)) => {
+            let expected = append_time_entry(&old_mmr, &time_entry(new_info))
+                .map_err(|_| Error::InvalidTimeMmr)?;
+            if expected.as_slice() != new_mmr.as_slice() {
+                debug!("Update must append exactly the new time info to the MMR!");
+                return Err(Error::TimeMmrMismatch);
+            }
+            Ok(())
+        }
+        _ => {
+            debug!("Update must neither add nor remove the MMR!");
+            Err(Error::TimeMmrMismatch)
+        }
+    }
+}
+
+/// A time cell is created without MMR or with an empty one, the genesis
+/// TimeInfo is not part of the MMR.
+fn validate_created_time_mmr(mmr: Option<&TimeMmr>) -> Result<(), Error> {
+    if mmr.is_some_and(|mmr| u64::from(mmr.leaf_count()) > 0) {
+        debug!("Time cell must be created with an empty MMR!");
+        return Err(Error::InvalidTimeMmr);
+    }
+    Ok(())
+}
+
+/// Updating the time cell must move the oracle forward, both timestamp and
+/// block number of the new TimeInfo must be strictly greater than the old ones,
+/// and sequence must count the updates, being the old sequence plus one. Time
+/// cells of the legacy testnet deployment may still hold empty data, their
+/// first update sets the genesis TimeInfo like a creation does.
+pub fn validate_time_update<T: TxView>(tx: &T, new_info: &TimeInfo) -> Result<(), Error> {
+    if is_legacy_time_cell(tx)? {
+        debug!("Updating a legacy time cell with empty data");
+        return Ok(());
+    }
+    let old_info = load_time_info(tx, 0, Source::GroupInput)?;
+
+    let old_timestamp = u64::from(old_info.timestamp());
+    let new_timestamp = u64::from(new_info.timestamp());
+    if new_timestamp <= old_timestamp {
+        debug!(
+            "Timestamp must increase! old: {}, new: {}",
+            old_timestamp, new_timestamp
+        );
+        return Err(Error::TimeInfoRegression);
+    }
+
+    let old_block_number = u64::from(old_info.block_number());
+    let new_block_number = u64::from(new_info.block_number());
+    if new_block_number <= old_block_number {
+        debug!(
+            "Block number must increase! old: {}, new: {}",
+            old_block_number, new_block_number
+        );
+        return Err(Error::TimeInfoRegression);
+    }
+
+    let old_sequence = u64::from(old_info.sequence());
+    let new_sequence = u64::from(new_info.sequence());
+    if old_sequence.checked_add(1) != Some(new_sequence) {
+        debug!(
+            "Sequence must increase by one! old: {}, new: {}",
+            old_sequence, new_sequence
+        );
+        return Err(Error::InvalidSequence);
+    }
+    Ok(())
+}
+
+fn is_legacy_time_cell<T: TxView>(tx: &T) -> Result<bool, Error> {
+    match tx.load_cell_data(&mut [], 0, 0, Source::GroupInput) {
+        Ok(len) => Ok(len == 0),
+        Err(SysError::LengthNotEnough(_)) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Loading the absolute timestamp since of the time cell input in milliseconds.
+fn load_time_cell_since<T: TxView>(tx: &T) -> Result<u64, Error> {
+    let since = Since::new(tx.load_input_since(0, Source::GroupInput)?);
+    match since.extract_lock_value() {
+        Some(LockValue::Timestamp(timestamp)) if since.is_absolute() && since.flags_is_valid() => {
+            Ok(timestamp)
+        }
+        _ => {
+            debug!("Time cell input must use an absolute timestamp since!");
+            Err(Error::InvalidSince)
+        }
+    }
+}
+
+/// The time cell input must carry an absolute timestamp since. CKB only accepts
+/// the transaction once the median time of past blocks reaches it, so the new
+/// timestamp can be at most SINCE_TOLERANCE ahead of the chain.
+pub fn validate_since_lower_bound<T: TxView>(tx: &T, new_info: &TimeInfo) -> Result<(), Error> {
+    let since_timestamp = load_time_cell_since(tx)?;
+    let new_timestamp = u64::from(new_info.timestamp());
+    if since_timestamp < new_timestamp.saturating_sub(SINCE_TOLERANCE) {
+        debug!(
+            "Timestamp is ahead of since! since: {}, new: {}",
+            since_timestamp, new_timestamp
+        );
+        return Err(Error::TimestampInFuture);
+    }
+    Ok(())
+}
+
+/// The update must reference the header of the block claimed by the new
+/// TimeInfo in header_deps, and the claimed timestamp must be within window
+/// after that header's timestamp.
+pub fn validate_header_dep<T: TxView>(
+    tx: &T,
+    new_info: &TimeInfo,
+    window: u64,
+) -> Result<(), Error> {
+    let block_number = u64::from(new_info.block_number());
+    let epoch = u64::from(new_info.epoch());
+
+    let mut i = 0;
+    let header = loop {
+        let header = match tx.load_header(i, Source::HeaderDep) {
+            Ok(header) => header,
+            Err(SysError::IndexOutOfBound) => {
+                debug!("Header dep of block {} is missing!", block_number);
+                return Err(Error::HeaderDepMissing);
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let raw = header.raw();
+        if raw.number().unpack() == block_number && raw.epoch().unpack() == epoch {
+            break header;
+        }
+        i += 1
+    };
+
+    let header_timestamp: u64 = header.raw().timestamp().unpack();
+    let new_timestamp = u64::from(new_info.timestamp());
+    if new_timestamp < header_timestamp || new_timestamp - header_timestamp > window {
+        debug!(
+            "Timestamp is out of header dep window! header: {}, new: {}",
+            header_timestamp, new_timestamp
+        );
+        return Err(Error::TimestampOutOfHeaderWindow);
+    }
+    Ok(())
+}
+
+/// Loading the args extension following type_id and always_success hash.
+pub fn load_args_extension_from_script_args<T: TxView>(
+    tx: &T,
+    offset: usize,
+) -> Result<ArgsExtension, Error> {
+    let script = tx.load_script()?;
+    let args = script.as_reader().args();
+    parse_args_extension(args.raw_data().get(offset..).unwrap_or_default())
+}
+
+/// Parsing the args extension, legacy args without extension use the default
+/// header dep window and have neither update interval nor fee limit nor keeper
+/// list.
+pub fn parse_args_extension(extension: &[u8]) -> Result<ArgsExtension, Error> {
+    if extension.is_empty() {
+        return Ok(ArgsExtension::default());
+    }
+    let field_count = match extension[0] {
+        ARGS_VERSION_UPDATE_INTERVAL => 2,
+        ARGS_VERSION_UPDATE_INTERVAL_AND_FEE => 3,
+        ARGS_VERSION_TIME_ARGS => return parse_time_args(&extension[1..]),
+        version => {
+            debug!("Unsupported args version {}!", version);
+            return Err(Error::InvalidArgsVersion);
+        }
+    };
+    if extension.len() != 1 + field_count * 8 {
+        debug!(
+            "Length of args extension is incorrect! extension len is {}",
+            extension.len()
+        );
+        return Err(Error::InvalidArgsExtensionLength);
+    }
+
+    let mut fields = [0u64; 3];
+    for (i, field) in fields.iter_mut().take(field_count).enumerate() {
+        let start = 1 + i * 8;
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&extension[start..start + 8]);
+        *field = u64::from_le_bytes(buf);
+    }
+    Ok(ArgsExtension {
+        header_dep_window: fields[0],
+        min_update_interval: fields[1],
+        min_fee: fields[2],
+        ..Default::default()
+    })
+}
+
+/// Parsing the TimeArgs table in molecule compatible mode. Fields are only ever
+/// appended to TimeArgs, args carrying fields added after this script was
+/// built are accepted and the unknown fields are ignored.
+fn parse_time_args(data: &[u8]) -> Result<ArgsExtension, Error> {
+    let time_args = TimeArgsReader::from_compatible_slice(data).map_err(|_| {
+        debug!("Time args are not a valid TimeArgs table!");
+        Error::InvalidTimeArgs
+    })?;
+    let shard_index: u8 = time_args.shard_index().into();
+    let shard_count: u8 = time_args.shard_count().into();
+    if shard_index >= shard_count.max(1) {
+        debug!("Invalid shard {} of {}!", shard_index, shard_count);
+        return Err(Error::InvalidShard);
+    }
+    Ok(ArgsExtension {
+        header_dep_window: time_args.header_dep_window().into(),
+        min_update_interval: time_args.min_update_interval().into(),
+        min_fee: time_args.min_fee().into(),
+        keepers: time_args
+            .keepers()
+            .iter()
+            .map(|keeper| {
+                let mut hash = [0u8; 32];
+                hash.copy_from_slice(keeper.as_slice());
+                hash
+            })
+            .collect(),
+        shard_index,
+        shard_count,
+    })
+}
+
+/// The since of the time cell input must be at least min_update_interval after
+/// the timestamp of the block which created the time cell input, whose header
+/// must be present in header_deps.
+pub fn validate_update_interval<T: TxView>(tx: &T, min_update_interval: u64) -> Result<(), Error> {
+    let created_header = match tx.load_header(0, Source::GroupInput) {
+        Ok(header) => header,
+        Err(SysError::ItemMissing) => {
+            debug!("Header dep of the time cell input is missing!");
+            return Err(Error::CreatedHeaderMissing);
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let created_timestamp: u64 = created_header.raw().timestamp().unpack();
+
+    let since_timestamp = load_time_cell_since(tx)?;
+    if since_timestamp < created_timestamp.saturating_add(min_update_interval) {
+        debug!(
+            "Update is too frequent! created: {}, since: {}",
+            created_timestamp, since_timestamp
+        );
+        return Err(Error::UpdateTooFrequent);
+    }
+    Ok(())
+}
+
+/// The update transaction must burn at least min_fee shannons, that is the
+/// total input capacity must exceed the total output capacity by min_fee.
+pub fn validate_fee<T: TxView>(tx: &T, min_fee: u64) -> Result<(), Error> {
+    let inputs_capacity = sum_capacity(tx, Source::Input)?;
+    let outputs_capacity = sum_capacity(tx, Source::Output)?;
+    let fee = inputs_capacity.saturating_sub(outputs_capacity);
+    if fee < min_fee {
+        debug!("Fee is too low! fee: {}, min fee: {}", fee, min_fee);
+        return Err(Error::FeeTooLow);
+    }
+    Ok(())
+}
+
+/// One input of the update transaction must be locked by a keeper listed in
+/// the time args, the time cell itself is locked by always_success so the
+/// keeper proves itself with another input, typically the one paying the fee.
+pub fn validate_keeper<T: TxView>(tx: &T, keepers: &[[u8; 32]]) -> Result<(), Error> {
+    let mut i = 0;
+    loop {
+        match tx.load_cell_lock_hash(i, Source::Input) {
+            Ok(lock_hash) if keepers.contains(&lock_hash) => return Ok(()),
+            Ok(_) => {}
+            Err(SysError::IndexOutOfBound) => {
+                debug!("No input is locked by an allowed keeper!");
+                return Err(Error::KeeperNotAllowed);
+            }
+            Err(e) => return Err(e.into()),
+        }
+        i += 1;
+    }
+}
+
+fn sum_capacity<T: TxView>(tx: &T, source: Source) -> Result<u64, Error> {
+    let mut sum = 0u64;
+    let mut i = 0;
+    loop {
+        match tx.load_cell_capacity(i, source) {
+            Ok(capacity) => sum = sum.saturating_add(capacity),
+            Err(SysError::IndexOutOfBound) => return Ok(sum),
+            Err(e) => return Err(e.into()),
+        }
+        i += 1;
+    }
+}
@@ -0,0 +1,158 @@
+use alloc::vec::Vec;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::packed::{CellInput, Header, Script},
+    error::SysError,
+};
+use molecule::prelude::Entity;
+
+use crate::TxView;
+
+/// A cell in the mock transaction.
+#[derive(Clone, Default)]
+pub struct MockCell {
+    pub capacity: u64,
+    pub lock_hash: [u8; 32],
+    pub type_hash: Option<[u8; 32]>,
+    pub data: Vec<u8>,
+}
+
+/// An input of the mock transaction, header is the header of the block which
+/// committed the cell. It is only visible when also present in header_deps.
+#[derive(Clone, Default)]
+pub struct MockInput {
+    pub input: CellInput,
+    pub cell: MockCell,
+    pub header: Option<Header>,
+}
+
+/// In-memory transaction verified against the type script `script`.
+#[derive(Clone, Default)]
+pub struct MockTx {
+    pub script: Script,
+    pub inputs: Vec<MockInput>,
+    pub outputs: Vec<MockCell>,
+    pub cell_deps: Vec<MockCell>,
+    pub header_deps: Vec<Header>,
+}
+
+impl MockTx {
+    pub fn script_hash(&self) -> [u8; 32] {
+        let mut ret = [0; 32];
+        ret.copy_from_slice(self.script.calc_script_hash().as_slice());
+        ret
+    }
+
+    fn is_in_group(&self, cell: &MockCell) -> bool {
+        cell.type_hash == Some(self.script_hash())
+    }
+
+    fn input(&self, index: usize, source: Source) -> Result<&MockInput, SysError> {
+        match source {
+            Source::Input => self.inputs.get(index),
+            Source::GroupInput => self
+                .inputs
+                .iter()
+                .filter(|input| self.is_in_group(&input.cell))
+                .nth(index),
+            _ => None,
+        }
+        .ok_or(SysError::IndexOutOfBound)
+    }
+
+    fn cell(&self, index: usize, source: Source) -> Result<&MockCell, SysError> {
+        match source {
+            Source::Input | Source::GroupInput => return Ok(&self.input(index, source)?.cell),
+            Source::Output => self.outputs.get(index),
+            Source::GroupOutput => self
+                .outputs
+                .iter()
+                .filter(|cell| self.is_in_group(cell))
+                .nth(index),
+            Source::CellDep => self.cell_deps.get(index),
+            _ => None,
+        }
+        .ok_or(SysError::IndexOutOfBound)
+    }
+}
+
+impl TxView for MockTx {
+    fn load_script(&self) -> Result<Script, SysError> {
+        Ok(self.script.clone())
+    }
+
+    fn load_script_hash(&self) -> Result<[u8; 32], SysError> {
+        Ok(self.script_hash())
+    }
+
+    fn load_input(&self, index: usize, source: Source) -> Result<CellInput, SysError> {
+        Ok(self.input(index, source)?.input.clone())
+    }
+
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        let since = self.input(index, source)?.input.since();
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(since.as_slice());
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        Ok(self.cell(index, source)?.capacity)
+    }
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
+        Ok(self.cell(index, source)?.lock_hash)
+    }
+
+    fn load_cell_type_hash(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<Option<[u8; 32]>, SysError> {
+        Ok(self.cell(index, source)?.type_hash)
+    }
+
+    fn load_cell_data(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        let data = &self.cell(index, source)?.data;
+        let data = data.get(offset..).unwrap_or_default();
+        let len = data.len().min(buf.len());
+        buf[..len].copy_from_slice(&data[..len]);
+        if data.len() > buf.len() {
+            return Err(SysError::LengthNotEnough(data.len()));
+        }
+        Ok(data.len())
+    }
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError> {
+        match source {
+            Source::HeaderDep => self
+                .header_deps
+                .get(index)
+                .cloned()
+                .ok_or(SysError::IndexOutOfBound),
+            Source::Input | Source::GroupInput => {
+                let header = self
+                    .input(index, source)?
+                    .header
+                    .as_ref()
+                    .ok_or(SysError::ItemMissing)?;
+                if self
+                    .header_deps
+                    .iter()
+                    .any(|dep| dep.as_slice() == header.as_slice())
+                {
+                    Ok(header.clone())
+                } else {
+                    Err(SysError::ItemMissing)
+                }
+            }
+            _ => Err(SysError::IndexOutOfBound),
+        }
+    }
+}
@@ -0,0 +1,641 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use ckb_hash::new_blake2b;
+use ckb_std::ckb_types::{
+    packed::{Byte32, CellInput, Header, OutPoint, RawHeader, Script},
+    prelude::*,
+};
+use time_oracle_reader::{
+    TIME_INFO_VERSION, TimeEntry, TimeInfo, TimeMmr, append_time_entry, parse_time_info, time_entry,
+};
+use time_oracle_types::{Byte32Vec, TimeArgs};
+
+use crate::mock::{MockCell, MockInput, MockTx};
+use crate::{ARGS_VERSION_TIME_ARGS, ArgsExtension, Error, parse_args_extension, verify};
+
+const LOCK_HASH: [u8; 32] = [7; 32];
+
+fn time_info_data(timestamp: u64, block_number: u64) -> Vec<u8> {
+    TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(timestamp.into())
+        .block_number(block_number.into())
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+// TimeInfo of the update following a time cell holding time_info_data.
+fn next_time_info_data(timestamp: u64, block_number: u64) -> Vec<u8> {
+    TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(timestamp.into())
+        .block_number(block_number.into())
+        .sequence(1.into())
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+fn timestamp_since(seconds: u64) -> u64 {
+    0x4000_0000_0000_0000 | seconds
+}
+
+fn header(number: u64, timestamp: u64) -> Header {
+    Header::new_builder()
+        .raw(
+            RawHeader::new_builder()
+                .number(number.pack())
+                .timestamp(timestamp.pack())
+                .build(),
+        )
+        .build()
+}
+
+fn cell_input(index: u32, since: u64) -> CellInput {
+    CellInput::new_builder()
+        .previous_output(
+            OutPoint::new_builder()
+                .tx_hash(Byte32::new([index as u8; 32]))
+                .index(index.pack())
+                .build(),
+        )
+        .since(since.pack())
+        .build()
+}
+
+fn time_script(type_id: [u8; 32], args_extension: &[u8]) -> Script {
+    Script::new_builder()
+        .code_hash(Byte32::new([9; 32]))
+        .hash_type(1.into())
+        .args(
+            [type_id.as_slice(), &LOCK_HASH, args_extension]
+                .concat()
+                .pack(),
+        )
+        .build()
+}
+
+fn time_cell(tx: &MockTx, capacity: u64, data: Vec<u8>) -> MockCell {
+    MockCell {
+        capacity,
+        lock_hash: LOCK_HASH,
+        type_hash: Some(tx.script_hash()),
+        data,
+    }
+}
+
+// A transaction creating the time cell on output index.
+fn create_tx(index: usize) -> MockTx {
+    let input = cell_input(0, 0);
+    let mut hasher = new_blake2b();
+    hasher.update(input.as_slice());
+    hasher.update(&(index as u64).to_le_bytes());
+    let mut type_id = [0; 32];
+    hasher.finalize(&mut type_id);
+
+    let mut tx = MockTx {
+        script: time_script(type_id, &[]),
+        inputs: vec![MockInput {
+            input,
+            cell: MockCell {
+                capacity: 1000,
+                lock_hash: LOCK_HASH,
+                ..Default::default()
+            },
+            header: None,
+        }],
+        ..Default::default()
+    };
+    tx.outputs = vec![MockCell::default(); index];
+    tx.outputs
+        .push(time_cell(&tx, 1000, time_info_data(1_700_000_000_000, 100)));
+    tx
+}
+
+// A valid transaction updating the time cell from block 100 to block 150.
+fn update_tx(args_extension: &[u8]) -> MockTx {
+    let mut tx = MockTx {
+        script: time_script([1; 32], args_extension),
+        header_deps: vec![header(150, 1_700_000_600_000)],
+        ..Default::default()
+    };
+    tx.inputs = vec![MockInput {
+        input: cell_input(0, timestamp_since(1_700_000_600)),
+        cell: time_cell(&tx, 1000, time_info_data(1_700_000_000_000, 100)),
+        header: None,
+    }];
+    tx.outputs = vec![time_cell(
+        &tx,
+        1000,
+        next_time_info_data(1_700_000_600_000, 150),
+    )];
+    tx
+}
+
+#[test]
+fn create_success() {
+    assert_eq!(verify(&create_tx(0)), Ok(()));
+}
+
+#[test]
+fn create_on_second_output() {
+    assert_eq!(verify(&create_tx(1)), Ok(()));
+}
+
+#[test]
+fn create_with_wrong_type_id() {
+    let mut tx = create_tx(0);
+    tx.script = time_script([1; 32], &[]);
+    tx.outputs[0].type_hash = Some(tx.script_hash());
+    assert_eq!(verify(&tx), Err(Error::TypeIDNotMatch));
+}
+
+#[test]
+fn create_two_time_cells() {
+    let mut tx = create_tx(0);
+    tx.outputs.push(tx.outputs[0].clone());
+    assert_eq!(verify(&tx), Err(Error::MultipleTimeOutputs));
+}
+
+#[test]
+fn create_with_short_args() {
+    let mut tx = create_tx(0);
+    tx.script = tx.script.as_builder().args([1u8; 4].pack()).build();
+    assert_eq!(verify(&tx), Err(Error::ArgsLengthNotEnough));
+}
+
+#[test]
+fn create_with_other_lock() {
+    let mut tx = create_tx(0);
+    tx.outputs[0].lock_hash = [0; 32];
+    assert_eq!(verify(&tx), Err(Error::InvalidTypeIDLock));
+}
+
+#[test]
+fn create_with_invalid_time_info() {
+    for data in [
+        vec![],
+        TimeInfo::new_builder()
+            .version((TIME_INFO_VERSION + 1).into())
+            .build()
+            .as_slice()
+            .to_vec(),
+    ] {
+        let mut tx = create_tx(0);
+        tx.outputs[0].data = data;
+        assert_eq!(verify(&tx), Err(Error::InvalidTimeInfo));
+    }
+}
+
+#[test]
+fn update_success() {
+    assert_eq!(verify(&update_tx(&[])), Ok(()));
+}
+
+#[test]
+fn not_allow_destroy() {
+    let mut tx = update_tx(&[]);
+    tx.outputs[0].type_hash = None;
+    assert_eq!(verify(&tx), Err(Error::TimeCellDestroyed));
+}
+
+#[test]
+fn update_two_time_cells() {
+    let mut tx = update_tx(&[]);
+    tx.inputs.push(tx.inputs[0].clone());
+    assert_eq!(verify(&tx), Err(Error::MultipleTimeInputs));
+}
+
+#[test]
+fn update_drain_capacity() {
+    let mut tx = update_tx(&[]);
+    tx.outputs[0].capacity = 999;
+    assert_eq!(verify(&tx), Err(Error::CapacityDecreased));
+}
+
+#[test]
+fn update_with_regression() {
+    for (timestamp, block_number) in [
+        (1_700_000_000_000, 150),
+        (1_699_999_400_000, 150),
+        (1_700_000_600_000, 100),
+    ] {
+        let mut tx = update_tx(&[]);
+        tx.outputs[0].data = next_time_info_data(timestamp, block_number);
+        assert_eq!(verify(&tx), Err(Error::TimeInfoRegression));
+    }
+}
+
+#[test]
+fn update_with_invalid_sequence() {
+    for sequence in [0, 2, u64::MAX] {
+        let mut tx = update_tx(&[]);
+        let time_info = parse_time_info(&tx.outputs[0].data).unwrap();
+        tx.outputs[0].data = time_info
+            .as_builder()
+            .sequence(sequence.into())
+            .build()
+            .as_slice()
+            .to_vec();
+        assert_eq!(verify(&tx), Err(Error::InvalidSequence));
+    }
+
+    // sequence can not wrap around
+    let mut tx = update_tx(&[]);
+    let time_info = parse_time_info(&tx.inputs[0].cell.data).unwrap();
+    tx.inputs[0].cell.data = time_info
+        .as_builder()
+        .sequence(u64::MAX.into())
+        .build()
+        .as_slice()
+        .to_vec();
+    let time_info = parse_time_info(&tx.outputs[0].data).unwrap();
+    tx.outputs[0].data = time_info
+        .as_builder()
+        .sequence(0.into())
+        .build()
+        .as_slice()
+        .to_vec();
+    assert_eq!(verify(&tx), Err(Error::InvalidSequence));
+}
+
+#[test]
+fn update_legacy_time_cell() {
+    // time cells of the legacy testnet deployment were created with empty data
+    let mut tx = update_tx(&[]);
+    tx.inputs[0].cell.data = Vec::new();
+    tx.outputs[0].data = time_info_data(1_700_000_600_000, 150);
+    assert_eq!(verify(&tx), Ok(()));
+
+    tx.inputs[0].cell.data = vec![0];
+    assert_eq!(verify(&tx), Err(Error::InvalidTimeInfo));
+}
+
+#[test]
+fn update_with_invalid_since() {
+    for since in [0, 0xc000_0000_0000_0000 | 600, 1_700_000_600] {
+        let mut tx = update_tx(&[]);
+        tx.inputs[0].input = cell_input(0, since);
+        assert_eq!(verify(&tx), Err(Error::InvalidSince));
+    }
+}
+
+#[test]
+fn update_ahead_of_since() {
+    let mut tx = update_tx(&[]);
+    tx.inputs[0].input = cell_input(0, timestamp_since(1_699_999_999));
+    assert_eq!(verify(&tx), Err(Error::TimestampInFuture));
+}
+
+#[test]
+fn update_without_matching_header_dep() {
+    let mut tx = update_tx(&[]);
+    tx.header_deps = vec![header(149, 1_700_000_600_000)];
+    assert_eq!(verify(&tx), Err(Error::HeaderDepMissing));
+}
+
+#[test]
+fn update_out_of_header_dep_window() {
+    for timestamp in [1_700_000_600_001, 1_699_999_999_999] {
+        let mut tx = update_tx(&[]);
+        tx.header_deps = vec![header(150, timestamp)];
+        assert_eq!(verify(&tx), Err(Error::TimestampOutOfHeaderWindow));
+    }
+}
+
+// Args extension carrying the header dep window in milliseconds.
+fn header_dep_window_args(header_dep_window: u64) -> Vec<u8> {
+    [
+        [1].as_slice(),
+        &header_dep_window.to_le_bytes(),
+        &0u64.to_le_bytes(),
+    ]
+    .concat()
+}
+
+#[test]
+fn update_with_header_dep_window() {
+    // header dep 20 minutes before the claimed timestamp
+    let mut tx = update_tx(&header_dep_window_args(0));
+    tx.header_deps = vec![header(150, 1_699_999_400_000)];
+    assert_eq!(verify(&tx), Err(Error::TimestampOutOfHeaderWindow));
+
+    let mut tx = update_tx(&header_dep_window_args(20 * 60 * 1000));
+    tx.header_deps = vec![header(150, 1_699_999_400_000)];
+    assert_eq!(verify(&tx), Ok(()));
+
+    let mut tx = update_tx(&header_dep_window_args(60 * 1000));
+    tx.header_deps = vec![header(150, 1_700_000_539_999)];
+    assert_eq!(verify(&tx), Err(Error::TimestampOutOfHeaderWindow));
+    tx.header_deps = vec![header(150, 1_700_000_540_000)];
+    assert_eq!(verify(&tx), Ok(()));
+}
+
+#[test]
+fn update_with_min_interval() {
+    let extension = [
+        [1].as_slice(),
+        &0u64.to_le_bytes(),
+        &600_000u64.to_le_bytes(),
+    ]
+    .concat();
+
+    let mut tx = update_tx(&extension);
+    let created = header(100, 1_700_000_000_000);
+    tx.inputs[0].header = Some(created.clone());
+    tx.header_deps.push(created.clone());
+    assert_eq!(verify(&tx), Ok(()));
+
+    tx.inputs[0].header = Some(header(100, 1_700_000_000_001));
+    tx.header_deps[1] = tx.inputs[0].header.clone().unwrap();
+    assert_eq!(verify(&tx), Err(Error::UpdateTooFrequent));
+
+    tx.header_deps.pop();
+    assert_eq!(verify(&tx), Err(Error::CreatedHeaderMissing));
+}
+
+#[test]
+fn update_with_min_fee() {
+    let extension = [
+        [2].as_slice(),
+        &0u64.to_le_bytes(),
+        &0u64.to_le_bytes(),
+        &100u64.to_le_bytes(),
+    ]
+    .concat();
+
+    let mut tx = update_tx(&extension);
+    assert_eq!(verify(&tx), Err(Error::FeeTooLow));
+
+    tx.inputs.push(MockInput {
+        input: cell_input(1, 0),
+        cell: MockCell {
+            capacity: 100,
+            ..Default::default()
+        },
+        header: None,
+    });
+    assert_eq!(verify(&tx), Ok(()));
+
+    tx.inputs[1].cell.capacity = 99;
+    assert_eq!(verify(&tx), Err(Error::FeeTooLow));
+}
+
+// Args extension of version 3 carrying a TimeArgs table.
+fn time_args(
+    header_dep_window: u64,
+    min_update_interval: u64,
+    min_fee: u64,
+    keepers: &[[u8; 32]],
+) -> Vec<u8> {
+    let time_args = TimeArgs::new_builder()
+        .header_dep_window(header_dep_window.into())
+        .min_update_interval(min_update_interval.into())
+        .min_fee(min_fee.into())
+        .keepers(
+            Byte32Vec::new_builder()
+                .set(keepers.iter().map(|hash| (*hash).into()).collect())
+                .build(),
+        )
+        .build();
+    [[ARGS_VERSION_TIME_ARGS].as_slice(), time_args.as_slice()].concat()
+}
+
+// Args extension of shard shard_index among shard_count shards.
+fn shard_args(shard_index: u8, shard_count: u8) -> Vec<u8> {
+    let time_args = TimeArgs::new_builder()
+        .shard_index(shard_index.into())
+        .shard_count(shard_count.into())
+        .build();
+    [[ARGS_VERSION_TIME_ARGS].as_slice(), time_args.as_slice()].concat()
+}
+
+#[test]
+fn create_shards() {
+    // three shards sharing the type id of output 1
+    let mut tx = create_tx(1);
+    let mut type_id = [0; 32];
+    type_id.copy_from_slice(&tx.script.args().raw_data()[..32]);
+    let scripts: Vec<_> = (0..3)
+        .map(|i| time_script(type_id, &shard_args(i, 3)))
+        .collect();
+    tx.outputs.truncate(1);
+    for script in &scripts {
+        tx.script = script.clone();
+        let cell = time_cell(&tx, 1000, time_info_data(1_700_000_000_000, 100));
+        tx.outputs.push(cell);
+    }
+    for script in &scripts {
+        tx.script = script.clone();
+        assert_eq!(verify(&tx), Ok(()));
+    }
+
+    // shards must follow each other in the order of their index
+    tx.outputs.swap(1, 2);
+    for script in &scripts[..2] {
+        tx.script = script.clone();
+        assert_eq!(verify(&tx), Err(Error::TypeIDNotMatch));
+    }
+    tx.outputs.swap(0, 2);
+    tx.script = scripts[1].clone();
+    assert_eq!(verify(&tx), Err(Error::TypeIDNotMatch));
+
+    // no output before the first shard can hold the last one
+    tx.outputs.swap(0, 3);
+    tx.script = scripts[2].clone();
+    assert_eq!(verify(&tx), Err(Error::InvalidShardPosition));
+}
+
+#[test]
+fn invalid_shard() {
+    assert_eq!(
+        parse_args_extension(&shard_args(3, 3)),
+        Err(Error::InvalidShard)
+    );
+    assert_eq!(
+        parse_args_extension(&shard_args(1, 0)),
+        Err(Error::InvalidShard)
+    );
+    assert_eq!(
+        verify(&update_tx(&shard_args(2, 2))),
+        Err(Error::InvalidShard)
+    );
+    assert_eq!(verify(&update_tx(&shard_args(1, 2))), Ok(()));
+}
+
+#[test]
+fn update_with_time_args() {
+    let mut tx = update_tx(&time_args(0, 0, 100, &[]));
+    assert_eq!(verify(&tx), Err(Error::FeeTooLow));
+
+    tx.inputs.push(MockInput {
+        input: cell_input(1, 0),
+        cell: MockCell {
+            capacity: 100,
+            lock_hash: [8; 32],
+            ..Default::default()
+        },
+        header: None,
+    });
+    assert_eq!(verify(&tx), Ok(()));
+
+    tx.script = time_script([1; 32], &time_args(0, 0, 100, &[[6; 32], [8; 32]]));
+    tx.inputs[0].cell.type_hash = Some(tx.script_hash());
+    tx.outputs[0].type_hash = Some(tx.script_hash());
+    assert_eq!(verify(&tx), Ok(()));
+
+    tx.inputs[1].cell.lock_hash = [5; 32];
+    assert_eq!(verify(&tx), Err(Error::KeeperNotAllowed));
+}
+
+#[test]
+fn parse_time_args() {
+    assert_eq!(
+        parse_args_extension(&time_args(60_000, 600_000, 100, &[[8; 32]])),
+        Ok(ArgsExtension {
+            header_dep_window: 60_000,
+            min_update_interval: 600_000,
+            min_fee: 100,
+            keepers: vec![[8; 32]],
+            ..Default::default()
+        })
+    );
+    // legacy args of the testnet deployment end after the lock hash
+    assert_eq!(parse_args_extension(&[]), Ok(ArgsExtension::default()));
+}
+
+#[test]
+fn parse_time_args_with_extra_fields() {
+    // TimeArgs built by a later version appending a field unknown to the script
+    let time_args = TimeArgs::new_builder().min_fee(100.into()).build();
+    let table = time_args.as_slice();
+    let field_count = TimeArgs::FIELD_COUNT;
+    let number = |i: usize| u32::from_le_bytes(table[i * 4..i * 4 + 4].try_into().unwrap());
+    let mut extended = vec![ARGS_VERSION_TIME_ARGS];
+    extended.extend_from_slice(&(number(0) + 5).to_le_bytes());
+    for i in 1..=field_count {
+        extended.extend_from_slice(&(number(i) + 4).to_le_bytes());
+    }
+    extended.extend_from_slice(&(number(0) + 4).to_le_bytes());
+    extended.extend_from_slice(&table[(field_count + 1) * 4..]);
+    extended.push(1);
+    assert_eq!(
+        parse_args_extension(&extended),
+        Ok(ArgsExtension {
+            min_fee: 100,
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn update_with_invalid_args_extension() {
+    assert_eq!(verify(&update_tx(&[4; 17])), Err(Error::InvalidArgsVersion));
+    assert_eq!(verify(&update_tx(&[3; 9])), Err(Error::InvalidTimeArgs));
+    for extension in [[1; 9].as_slice(), &[1; 25], &[2; 17]] {
+        assert_eq!(
+            verify(&update_tx(extension)),
+            Err(Error::InvalidArgsExtensionLength)
+        );
+    }
+}
+
+#[test]
+fn unknown_sys_error() {
+    assert_eq!(
+        Error::from(ckb_std::error::SysError::Unknown(99)),
+        Error::UnknownSysError
+    );
+}
+
+// Data of a time cell storing mmr after its TimeInfo.
+fn with_mmr(data: &[u8], mmr: &TimeMmr) -> Vec<u8> {
+    [data, mmr.as_slice()].concat()
+}
+
+// MMR of the entries of every update after the time cell was created.
+fn time_mmr(entries: &[TimeEntry]) -> TimeMmr {
+    entries.iter().fold(TimeMmr::default(), |mmr, entry| {
+        append_time_entry(&mmr, entry).unwrap()
+    })
+}
+
+fn entry(sequence: u64) -> TimeEntry {
+    TimeEntry::new_builder()
+        .sequence(sequence.into())
+        .timestamp((1_700_000_000_000 + sequence).into())
+        .block_number((100 + sequence).into())
+        .build()
+}
+
+#[test]
+fn create_with_mmr() {
+    let mut tx = create_tx(0);
+    tx.outputs[0].data = with_mmr(&tx.outputs[0].data, &TimeMmr::default());
+    assert_eq!(verify(&tx), Ok(()));
+
+    tx.outputs[0].data = with_mmr(
+        &time_info_data(1_700_000_000_000, 100),
+        &time_mmr(&[entry(1)]),
+    );
+    assert_eq!(verify(&tx), Err(Error::InvalidTimeMmr));
+
+    tx.outputs[0].data = [time_info_data(1_700_000_000_000, 100), vec![0]].concat();
+    assert_eq!(verify(&tx), Err(Error::InvalidTimeMmr));
+
+    // peaks must match the leaf count
+    let mmr = time_mmr(&[entry(1), entry(2), entry(3)])
+        .as_builder()
+        .leaf_count(4.into())
+        .build();
+    tx.outputs[0].data = with_mmr(&time_info_data(1_700_000_000_000, 100), &mmr);
+    assert_eq!(verify(&tx), Err(Error::InvalidTimeMmr));
+}
+
+#[test]
+fn update_with_mmr() {
+    let history: Vec<TimeEntry> = (1..=6).map(entry).collect();
+    let mut tx = update_tx(&[]);
+    let new_info = parse_time_info(&tx.outputs[0].data).unwrap();
+    let old_mmr = time_mmr(&history);
+    tx.inputs[0].cell.data = with_mmr(&tx.inputs[0].cell.data, &old_mmr);
+    let new_data = tx.outputs[0].data.clone();
+
+    let new_mmr = append_time_entry(&old_mmr, &time_entry(&new_info)).unwrap();
+    assert_eq!(u64::from(new_mmr.leaf_count()), 7);
+    assert_eq!(new_mmr.peaks().len(), 3);
+    tx.outputs[0].data = with_mmr(&new_data, &new_mmr);
+    assert_eq!(verify(&tx), Ok(()));
+
+    // appending another entry, or none
+    for mmr in [
+        append_time_entry(&old_mmr, &entry(7)).unwrap(),
+        old_mmr.clone(),
+    ] {
+        tx.outputs[0].data = with_mmr(&new_data, &mmr);
+        assert_eq!(verify(&tx), Err(Error::TimeMmrMismatch));
+    }
+
+    // dropping the MMR
+    tx.outputs[0].data = new_data.clone();
+    assert_eq!(verify(&tx), Err(Error::TimeMmrMismatch));
+
+    // starting a MMR on a live time cell
+    let mut tx = update_tx(&[]);
+    tx.outputs[0].data = with_mmr(&new_data, &time_mmr(&[time_entry(&new_info)]));
+    assert_eq!(verify(&tx), Err(Error::TimeMmrMismatch));
+}
+
+#[test]
+fn update_legacy_time_cell_with_mmr() {
+    // the first update of a legacy time cell may start an empty MMR
+    let mut tx = update_tx(&[]);
+    tx.inputs[0].cell.data = Vec::new();
+    let new_data = time_info_data(1_700_000_600_000, 150);
+    tx.outputs[0].data = with_mmr(&new_data, &TimeMmr::default());
+    assert_eq!(verify(&tx), Ok(()));
+
+    tx.outputs[0].data = with_mmr(&new_data, &time_mmr(&[entry(1)]));
+    assert_eq!(verify(&tx), Err(Error::InvalidTimeMmr));
+}
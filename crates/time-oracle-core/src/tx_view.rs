@@ -0,0 +1,92 @@
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::packed::{CellInput, Header, Script},
+    error::SysError,
+    high_level, syscalls,
+};
+
+/// Read access to the transaction being verified. Methods mirror the ckb-std
+/// syscalls of the same name, so that the validation rules can run both on-chain
+/// and against an in-memory transaction.
+pub trait TxView {
+    fn load_script(&self) -> Result<Script, SysError>;
+
+    fn load_script_hash(&self) -> Result<[u8; 32], SysError>;
+
+    fn load_input(&self, index: usize, source: Source) -> Result<CellInput, SysError>;
+
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError>;
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError>;
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError>;
+
+    fn load_cell_type_hash(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<Option<[u8; 32]>, SysError>;
+
+    /// Loading cell data into buf starting at offset, returns the full data
+    /// length or LengthNotEnough when buf is too small.
+    fn load_cell_data(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError>;
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError>;
+}
+
+/// TxView backed by CKB syscalls, used by the time contract.
+pub struct OnChainTx;
+
+impl TxView for OnChainTx {
+    fn load_script(&self) -> Result<Script, SysError> {
+        high_level::load_script()
+    }
+
+    fn load_script_hash(&self) -> Result<[u8; 32], SysError> {
+        high_level::load_script_hash()
+    }
+
+    fn load_input(&self, index: usize, source: Source) -> Result<CellInput, SysError> {
+        high_level::load_input(index, source)
+    }
+
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        high_level::load_input_since(index, source)
+    }
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        high_level::load_cell_capacity(index, source)
+    }
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
+        high_level::load_cell_lock_hash(index, source)
+    }
+
+    fn load_cell_type_hash(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<Option<[u8; 32]>, SysError> {
+        high_level::load_cell_type_hash(index, source)
+    }
+
+    fn load_cell_data(
+        &self,
+        buf: &mut [u8],
+        offset: usize,
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        syscalls::load_cell_data(buf, offset, index, source)
+    }
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError> {
+        high_level::load_header(index, source)
+    }
+}
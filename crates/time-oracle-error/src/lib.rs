@@ -0,0 +1,244 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Exit codes of the time type script, the time lock script and the example
+//! contracts reading the oracle, shared by the contracts, the tests and
+//! off-chain tooling.
+//!
+//! ```
+//! use time_oracle_error::Error;
+//!
+//! let err = Error::try_from(23).unwrap();
+//! assert_eq!(err, Error::InvalidTypeIDLock);
+//! assert_eq!(
+//!     err.to_string(),
+//!     "output lock is not the lock configured in the time type args"
+//! );
+//! ```
+
+#[cfg(feature = "ckb-std")]
+extern crate alloc;
+
+use core::fmt;
+
+#[cfg(test)]
+mod tests;
+
+// Declaring an exit code enum of a script. Codes 1 to 10 are the syscall
+// errors shared by every script, the listed variants take their own codes from
+// 20 on. Conversions from and to the exit code, Display and the conversion from
+// the syscall errors of ckb-std are generated for each enum.
+macro_rules! exit_codes {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident = $code:literal => $description:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(i8)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $name {
+            IndexOutOfBound = 1,
+            ItemMissing = 2,
+            LengthNotEnough = 3,
+            Encoding = 4,
+            WaitFailure = 5,
+            InvalidFd = 6,
+            OtherEndClosed = 7,
+            MaxVmsSpawned = 8,
+            MaxFdsCreated = 9,
+            UnknownSysError = 10,
+            $($variant = $code,)+
+        }
+
+        impl $name {
+            pub fn description(&self) -> &'static str {
+                match self {
+                    Self::IndexOutOfBound => "syscall index out of bound",
+                    Self::ItemMissing => "syscall item missing",
+                    Self::LengthNotEnough => "syscall buffer length not enough",
+                    Self::Encoding => "syscall data encoding error",
+                    Self::WaitFailure => "syscall wait failure",
+                    Self::InvalidFd => "syscall invalid fd",
+                    Self::OtherEndClosed => "syscall pipe other end closed",
+                    Self::MaxVmsSpawned => "syscall max vms spawned",
+                    Self::MaxFdsCreated => "syscall max fds created",
+                    Self::UnknownSysError => "syscall returned an unknown error code",
+                    $(Self::$variant => $description,)+
+                }
+            }
+        }
+
+        impl TryFrom<i8> for $name {
+            type Error = i8;
+
+            fn try_from(code: i8) -> Result<Self, i8> {
+                let err = match code {
+                    1 => Self::IndexOutOfBound,
+                    2 => Self::ItemMissing,
+                    3 => Self::LengthNotEnough,
+                    4 => Self::Encoding,
+                    5 => Self::WaitFailure,
+                    6 => Self::InvalidFd,
+                    7 => Self::OtherEndClosed,
+                    8 => Self::MaxVmsSpawned,
+                    9 => Self::MaxFdsCreated,
+                    10 => Self::UnknownSysError,
+                    $($code => Self::$variant,)+
+                    _ => return Err(code),
+                };
+                Ok(err)
+            }
+        }
+
+        impl From<$name> for i8 {
+            fn from(err: $name) -> i8 {
+                err as i8
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.description())
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for $name {}
+
+        #[cfg(feature = "ckb-std")]
+        impl From<ckb_std::error::SysError> for $name {
+            fn from(err: ckb_std::error::SysError) -> Self {
+                use ckb_std::error::SysError;
+
+                match err {
+                    SysError::IndexOutOfBound => Self::IndexOutOfBound,
+                    SysError::ItemMissing => Self::ItemMissing,
+                    SysError::LengthNotEnough(_) => Self::LengthNotEnough,
+                    SysError::Encoding => Self::Encoding,
+                    SysError::WaitFailure => Self::WaitFailure,
+                    SysError::InvalidFd => Self::InvalidFd,
+                    SysError::OtherEndClosed => Self::OtherEndClosed,
+                    SysError::MaxVmsSpawned => Self::MaxVmsSpawned,
+                    SysError::MaxFdsCreated => Self::MaxFdsCreated,
+                    SysError::Unknown(err_code) => {
+                        ckb_std::debug!("unexpected sys error {}", err_code);
+                        Self::UnknownSysError
+                    }
+                }
+            }
+        }
+    };
+}
+
+exit_codes! {
+    /// Exit codes of the time type script. Codes 1 to 10 are syscall errors, 20
+    /// and above are violated rules, every failure path returns its own code.
+    pub enum Error {
+        MultipleTimeInputs = 20 => "more than one input carries the time type",
+        TypeIDNotMatch = 21 => "type id in args does not match the first input",
+        ArgsLengthNotEnough = 22 => "time type args are too short",
+        InvalidTypeIDLock = 23 => "output lock is not the lock configured in the time type args",
+        InvalidTimeInfo = 24 => "time cell data is not a supported time info",
+        InvalidSequence = 25 => "sequence is not the old sequence plus one",
+        TimeInfoRegression = 26 => "timestamp or block number does not move forward",
+        InvalidSince = 27 => "time cell input has no absolute timestamp since",
+        TimestampInFuture = 28 => "timestamp is ahead of the since of the time cell input",
+        HeaderDepMissing = 29 => "no header dep matches block number and epoch",
+        TimestampOutOfHeaderWindow = 30 => "timestamp is outside the window after the header dep",
+        InvalidArgsVersion = 31 => "version of the args extension is not supported",
+        UpdateTooFrequent = 32 => "time cell is updated sooner than the minimum interval",
+        FeeTooLow = 33 => "update burns less capacity than the minimum fee",
+        CapacityDecreased = 34 => "capacity of the time cell decreased",
+        MultipleTimeOutputs = 35 => "more than one output carries the time type",
+        TimeCellDestroyed = 36 => "time cell is consumed without being recreated",
+        CreatedHeaderMissing = 37 =>
+            "header of the block creating the time cell input is not in header deps",
+        InvalidArgsExtensionLength = 38 =>
+            "length of the args extension does not match its version",
+        InvalidTimeArgs = 39 => "args extension is not a valid TimeArgs table",
+        KeeperNotAllowed = 40 => "no input is locked by a keeper listed in the time args",
+        InvalidShard = 41 => "shard index is not below the shard count",
+        InvalidShardPosition = 42 => "shard is created before the first shard",
+        InvalidTimeMmr = 43 => "data after the time info is not a valid TimeMmr",
+        TimeMmrMismatch = 44 => "update does not append exactly one entry to the MMR",
+    }
+}
+
+exit_codes! {
+    /// Exit codes of the time lock script, codes 1 to 10 are the same syscall
+    /// errors as in [`Error`].
+    pub enum TimeLockError {
+        ArgsLengthNotEnough = 20 => "time lock args are not the time type code hash and hash type",
+        InvalidTimeType = 21 => "type of an input locked by time lock is not the time type",
+        TimeOutputMissing = 22 => "no output carries the same time type and time lock",
+        InvalidTimeInfo = 23 => "time cell data is not a supported time info",
+        TimeTypeMissing = 24 => "input locked by time lock has no type",
+    }
+}
+
+exit_codes! {
+    /// Exit codes of the lending example, codes 1 to 10 are the same syscall
+    /// errors as in [`Error`].
+    pub enum LendingError {
+        ArgsLengthNotEnough = 20 => "lending args are not the time type hash and the yearly rate",
+        TimeCellMissing = 21 => "no cell dep carries the time type",
+        DuplicatedTimeCell = 22 => "more than one cell dep carries the time type",
+        InvalidTimeInfo = 23 => "time cell data is not a supported time info",
+        MissingTimeProof = 24 => "deposit transaction proves no current time",
+        StaleTimeInfo = 25 => "oracle is too old to timestamp a deposit",
+        InvalidDepositData = 26 => "cell data is neither empty nor a deposit timestamp",
+        InvalidDepositTime = 27 => "deposit timestamp is not the oracle timestamp",
+        InterestNotPaid = 28 =>
+            "lock of a withdrawn deposit receives less than principal and interest",
+        PoolOverdrawn = 29 => "pool shrinks by more than the interest paid",
+        DepositHeaderMissing = 30 => "header deps miss the block committing a withdrawn deposit",
+    }
+}
+
+exit_codes! {
+    /// Exit codes of the deadline lock example, codes 1 to 10 are the same syscall
+    /// errors as in [`Error`].
+    pub enum DeadlineLockError {
+        InvalidArgsLength = 20 =>
+            "deadline lock args are not the time type hash, deadline and optional owner",
+        TimeCellMissing = 21 => "no cell dep carries the time type",
+        DuplicatedTimeCell = 22 => "more than one cell dep carries the time type",
+        InvalidTimeInfo = 23 => "time cell data is not a supported time info",
+        MissingTimeProof = 24 => "transaction proves no current time to check the oracle against",
+        StaleTimeInfo = 25 => "oracle is too old to tell whether the deadline passed",
+        DeadlinePassed = 26 => "oracle or proven time has reached the deadline",
+        OwnerMissing = 27 => "no input is locked by the owner lock",
+    }
+}
+
+exit_codes! {
+    /// Exit codes of the vesting example, codes 1 to 10 are the same syscall errors
+    /// as in [`Error`].
+    pub enum VestingError {
+        InvalidArgsLength = 20 => "vesting args are not the time type hash",
+        TimeCellMissing = 21 => "no cell dep carries the time type",
+        DuplicatedTimeCell = 22 => "more than one cell dep carries the time type",
+        InvalidTimeInfo = 23 => "time cell data is not a supported time info",
+        InvalidSchedule = 24 =>
+            "cell data is not a schedule or the created cell does not hold its total",
+        MultipleVestingCells = 25 => "more than one vesting cell in inputs or outputs",
+        ScheduleChanged = 26 => "withdrawal changes the schedule",
+        LockChanged = 27 => "withdrawal moves the unvested capacity to another lock",
+        UnvestedWithdrawn = 28 => "withdrawal takes more than the vested capacity",
+    }
+}
+
+exit_codes! {
+    /// Exit codes of the checkpoint type script, codes 1 to 10 are the same syscall
+    /// errors as in [`Error`].
+    pub enum CheckpointError {
+        InvalidArgsLength = 20 => "checkpoint args are not the time type hash",
+        CheckpointSpent = 21 => "checkpoint cell is consumed",
+        TimeCellMissing = 22 => "no output carries the time type",
+        DuplicatedTimeCell = 23 => "more than one output carries the time type",
+        NotAnUpdate = 24 =>
+            "no input carries the time type, checkpoints are only created by updates",
+        CheckpointMismatch = 25 => "checkpoint data differs from the new time cell data",
+    }
+}
@@ -0,0 +1,32 @@
+extern crate std;
+
+use core::fmt;
+use std::string::ToString;
+
+use crate::{CheckpointError, DeadlineLockError, Error, LendingError, TimeLockError, VestingError};
+
+// Every code from 1 to 10 and from 20 to last decodes to an error with a
+// description and encodes back to itself, no other code decodes.
+fn assert_exit_codes<E>(last: i8)
+where
+    E: TryFrom<i8, Error = i8> + Into<i8> + Copy + fmt::Debug + fmt::Display,
+{
+    for code in (1..=10).chain(20..=last) {
+        let err = E::try_from(code).expect("known code");
+        assert_eq!(err.into(), code);
+        assert!(!err.to_string().is_empty());
+    }
+    for code in [0, 11, 19, last + 1, -1] {
+        assert_eq!(E::try_from(code).err(), Some(code));
+    }
+}
+
+#[test]
+fn error_from_exit_code() {
+    assert_exit_codes::<Error>(44);
+    assert_exit_codes::<TimeLockError>(24);
+    assert_exit_codes::<LendingError>(30);
+    assert_exit_codes::<DeadlineLockError>(27);
+    assert_exit_codes::<VestingError>(28);
+    assert_exit_codes::<CheckpointError>(25);
+}
@@ -0,0 +1,270 @@
+#![no_std]
+
+//! Helpers for CKB contracts reading the time oracle.
+//!
+//! Consumer contracts reference the live time cell in cell_deps and locate it
+//! by its type script hash:
+//!
+//! ```ignore
+//! let time_info = time_oracle_reader::find_time_cell(&TIME_TYPE_HASH)?;
+//! let now = u64::from(time_info.timestamp());
+//! ```
+//!
+//! A sharded oracle keeps several time cells updated independently, consumers
+//! reference any of them and read the freshest one:
+//!
+//! ```ignore
+//! let time_info = time_oracle_reader::find_freshest_time_cell(&SHARD_TYPE_HASHES)?;
+//! ```
+//!
+//! The oracle value is only as recent as the last update, so consumers should
+//! also check it against the time their own transaction can prove:
+//!
+//! ```ignore
+//! time_oracle_reader::assert_fresh(&time_info, 60 * 60 * 1000)?;
+//! time_oracle_reader::assert_before(&time_info, deadline)?;
+//! ```
+//!
+//! A time cell storing the MMR of its past values proves any of them with a
+//! TimeProof, e.g. carried in the witness of the consumer:
+//!
+//! ```ignore
+//! let mmr = time_oracle_reader::find_time_mmr(&TIME_TYPE_HASH)?;
+//! time_oracle_reader::verify_time_proof(&mmr, TimeProofReader::from_slice(&witness)?)?;
+//! ```
+
+extern crate alloc;
+
+pub mod mmr;
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::Unpack,
+    debug,
+    error::SysError,
+    high_level::{load_cell_type_hash, load_header, load_input_since},
+    since::{LockValue, Since},
+    syscalls::load_cell_data,
+};
+pub use mmr::{append_time_entry, parse_time_mmr, time_entry, time_mmr_root, verify_time_proof};
+use molecule::prelude::Reader;
+pub use time_oracle_types::{
+    TIME_INFO_VERSION, TimeEntry, TimeInfo, TimeInfoReader, TimeMmr, TimeProof, TimeProofReader,
+    Uint64,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// Syscall failed while reading the transaction
+    Syscall(SysError),
+    /// No cell dep carries the time type script
+    TimeCellMissing,
+    /// More than one cell dep carries the time type script
+    DuplicatedTimeCell,
+    /// Cell data is not a TimeInfo of the supported version
+    InvalidTimeInfo,
+    /// Data after the TimeInfo is not a TimeMmr whose peaks match its leaf count
+    InvalidTimeMmr,
+    /// Neither an absolute timestamp since nor a header dep proves current time
+    MissingTimeProof,
+    /// Time cell is older than the allowed age at the proven current time
+    StaleTimeInfo,
+    /// Oracle or proven current time has reached the deadline
+    DeadlinePassed,
+    /// Time cell stores no MMR of its past values
+    TimeMmrMissing,
+    /// Proof does not show its entry is in the MMR of the time cell
+    InvalidTimeProof,
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        Self::Syscall(err)
+    }
+}
+
+/// Locating the only cell dep whose type script hash is type_hash and loading
+/// its TimeInfo.
+pub fn find_time_cell(type_hash: &[u8; 32]) -> Result<TimeInfo, Error> {
+    load_time_info(find_time_cell_index(type_hash)?, Source::CellDep)
+}
+
+/// Locating the only cell dep whose type script hash is type_hash and loading
+/// the MMR of its past values.
+pub fn find_time_mmr(type_hash: &[u8; 32]) -> Result<TimeMmr, Error> {
+    load_time_mmr(find_time_cell_index(type_hash)?, Source::CellDep)?.ok_or_else(|| {
+        debug!("Time cell stores no MMR!");
+        Error::TimeMmrMissing
+    })
+}
+
+fn find_time_cell_index(type_hash: &[u8; 32]) -> Result<usize, Error> {
+    let mut found = None;
+    let mut i = 0;
+    loop {
+        match load_cell_type_hash(i, Source::CellDep) {
+            Ok(Some(hash)) if &hash == type_hash => {
+                if found.is_some() {
+                    debug!("Time cell is referenced more than once in cell deps!");
+                    return Err(Error::DuplicatedTimeCell);
+                }
+                found = Some(i);
+            }
+            Ok(_) => {}
+            Err(SysError::IndexOutOfBound) => break,
+            Err(e) => return Err(e.into()),
+        }
+        i += 1;
+    }
+
+    found.ok_or_else(|| {
+        debug!("Time cell is missing in cell deps!");
+        Error::TimeCellMissing
+    })
+}
+
+/// Loading the TimeInfo of every cell dep whose type script hash is one of
+/// type_hashes, the shards of one oracle, and returning the one with the
+/// latest timestamp.
+pub fn find_freshest_time_cell(type_hashes: &[[u8; 32]]) -> Result<TimeInfo, Error> {
+    let mut freshest: Option<TimeInfo> = None;
+    let mut i = 0;
+    loop {
+        match load_cell_type_hash(i, Source::CellDep) {
+            Ok(Some(hash)) if type_hashes.contains(&hash) => {
+                let time_info = load_time_info(i, Source::CellDep)?;
+                let is_fresher = freshest.as_ref().is_none_or(|freshest| {
+                    u64::from(time_info.timestamp()) > u64::from(freshest.timestamp())
+                });
+                if is_fresher {
+                    freshest = Some(time_info);
+                }
+            }
+            Ok(_) => {}
+            Err(SysError::IndexOutOfBound) => break,
+            Err(e) => return Err(e.into()),
+        }
+        i += 1;
+    }
+
+    freshest.ok_or_else(|| {
+        debug!("No shard of the time oracle is in cell deps!");
+        Error::TimeCellMissing
+    })
+}
+
+/// Loading the TimeInfo payload from cell data, the data must be exactly one
+/// molecule encoded TimeInfo of the supported version, optionally followed by
+/// a TimeMmr.
+pub fn load_time_info(index: usize, source: Source) -> Result<TimeInfo, Error> {
+    let mut buf = [0u8; TimeInfo::TOTAL_SIZE];
+    match load_cell_data(&mut buf, 0, index, source) {
+        Ok(len) if len == TimeInfo::TOTAL_SIZE => {}
+        Err(SysError::LengthNotEnough(len)) if len > TimeInfo::TOTAL_SIZE => {
+            load_time_mmr(index, source)?;
+        }
+        Ok(len) | Err(SysError::LengthNotEnough(len)) => {
+            debug!("Length of time info is incorrect! data len is {}", len);
+            return Err(Error::InvalidTimeInfo);
+        }
+        Err(e) => return Err(e.into()),
+    }
+    parse_time_info(&buf)
+}
+
+/// Loading the TimeMmr stored after TimeInfo in cell data, None when the data
+/// is only a TimeInfo.
+pub fn load_time_mmr(index: usize, source: Source) -> Result<Option<TimeMmr>, Error> {
+    let data = ckb_std::high_level::load_cell_data(index, source)?;
+    match data.get(TimeInfo::TOTAL_SIZE..) {
+        Some([]) => Ok(None),
+        Some(mmr) => parse_time_mmr(mmr).map(Some),
+        None => {
+            debug!(
+                "Length of time info is incorrect! data len is {}",
+                data.len()
+            );
+            Err(Error::InvalidTimeInfo)
+        }
+    }
+}
+
+/// Parsing cell data as one molecule encoded TimeInfo of the supported version.
+pub fn parse_time_info(data: &[u8]) -> Result<TimeInfo, Error> {
+    let time_info = TimeInfoReader::from_slice(data).map_err(|_| Error::InvalidTimeInfo)?;
+    if u8::from(time_info.version()) != TIME_INFO_VERSION {
+        debug!("Unsupported time info version!");
+        return Err(Error::InvalidTimeInfo);
+    }
+    Ok(time_info.to_entity())
+}
+
+/// Loading the latest timestamp in milliseconds proved by current transaction,
+/// which is the maximum of all absolute timestamp since of inputs and all header
+/// dep timestamps. CKB guarantees the real time is not earlier than it.
+pub fn load_proven_timestamp() -> Result<Option<u64>, Error> {
+    let mut proven = None;
+
+    let mut i = 0;
+    loop {
+        let since = match load_input_since(i, Source::Input) {
+            Ok(since) => Since::new(since),
+            Err(SysError::IndexOutOfBound) => break,
+            Err(e) => return Err(e.into()),
+        };
+        if since.is_absolute()
+            && since.flags_is_valid()
+            && let Some(LockValue::Timestamp(timestamp)) = since.extract_lock_value()
+        {
+            proven = proven.max(Some(timestamp));
+        }
+        i += 1;
+    }
+
+    let mut i = 0;
+    loop {
+        let header = match load_header(i, Source::HeaderDep) {
+            Ok(header) => header,
+            Err(SysError::IndexOutOfBound) => break,
+            Err(e) => return Err(e.into()),
+        };
+        proven = proven.max(Some(header.raw().timestamp().unpack()));
+        i += 1;
+    }
+    Ok(proven)
+}
+
+/// Asserting the oracle value is at most max_age milliseconds older than the
+/// time proved by current transaction. Consumers must carry an absolute
+/// timestamp since or a recent header dep, otherwise freshness cannot be told.
+pub fn assert_fresh(time_info: &TimeInfo, max_age: u64) -> Result<(), Error> {
+    let proven_timestamp = load_proven_timestamp()?.ok_or_else(|| {
+        debug!("No since or header dep proves current time!");
+        Error::MissingTimeProof
+    })?;
+    let timestamp = u64::from(time_info.timestamp());
+    if timestamp.saturating_add(max_age) < proven_timestamp {
+        debug!(
+            "Time info is stale! timestamp: {}, proven: {}, max age: {}",
+            timestamp, proven_timestamp, max_age
+        );
+        return Err(Error::StaleTimeInfo);
+    }
+    Ok(())
+}
+
+/// Asserting the current time is before deadline in milliseconds, that is
+/// neither the oracle value nor the time proved by current transaction has
+/// reached it. Combine with assert_fresh to bound how late the oracle may be.
+pub fn assert_before(time_info: &TimeInfo, deadline: u64) -> Result<(), Error> {
+    let timestamp = u64::from(time_info.timestamp());
+    let proven_timestamp = load_proven_timestamp()?.unwrap_or(0);
+    if timestamp >= deadline || proven_timestamp >= deadline {
+        debug!(
+            "Deadline passed! timestamp: {}, proven: {}, deadline: {}",
+            timestamp, proven_timestamp, deadline
+        );
+        return Err(Error::DeadlinePassed);
+    }
+    Ok(())
+}
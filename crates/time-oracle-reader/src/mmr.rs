@@ -0,0 +1,155 @@
+//! Merkle Mountain Range of past oracle values.
+//!
+//! A time cell may store a TimeMmr after its TimeInfo, committing to the
+//! TimeEntry of every update. Only the peaks are stored, appending a leaf and
+//! bagging the root work from them alone, so the time contract can check each
+//! update and consumers can verify a TimeProof without scanning the chain.
+
+use alloc::{vec, vec::Vec};
+use ckb_hash::{blake2b_256, new_blake2b};
+use ckb_merkle_mountain_range::{
+    MMR, MMRStore, Merge, MerkleProof, helper::get_peaks, leaf_index_to_mmr_size,
+    leaf_index_to_pos, util::MemStore,
+};
+use molecule::prelude::{Builder, Entity, Reader};
+use time_oracle_types::{
+    Byte32, Byte32Vec, TimeEntry, TimeInfo, TimeMmr, TimeMmrReader, TimeProofReader,
+};
+
+use crate::Error;
+
+/// Merging MMR nodes with the CKB default blake2b hash.
+pub struct MergeTimeEntry;
+
+impl Merge for MergeTimeEntry {
+    type Item = [u8; 32];
+
+    fn merge(
+        left: &Self::Item,
+        right: &Self::Item,
+    ) -> ckb_merkle_mountain_range::Result<Self::Item> {
+        let mut hasher = new_blake2b();
+        hasher.update(left);
+        hasher.update(right);
+        let mut hash = [0u8; 32];
+        hasher.finalize(&mut hash);
+        Ok(hash)
+    }
+}
+
+/// The entry an update appends to the MMR, taken from the new TimeInfo.
+pub fn time_entry(time_info: &TimeInfo) -> TimeEntry {
+    TimeEntry::new_builder()
+        .sequence(time_info.sequence())
+        .timestamp(time_info.timestamp())
+        .block_number(time_info.block_number())
+        .build()
+}
+
+/// Leaf of entry in the MMR, the blake2b hash of its molecule encoding.
+pub fn leaf_hash(entry: &TimeEntry) -> [u8; 32] {
+    blake2b_256(entry.as_slice())
+}
+
+/// Parsing the data stored after TimeInfo as one TimeMmr whose peaks match its
+/// leaf count, a MMR of n leaves has one peak per bit set in n.
+pub fn parse_time_mmr(data: &[u8]) -> Result<TimeMmr, Error> {
+    let mmr = TimeMmrReader::from_slice(data).map_err(|_| Error::InvalidTimeMmr)?;
+    if mmr.peaks().len() != u64::from(mmr.leaf_count()).count_ones() as usize {
+        return Err(Error::InvalidTimeMmr);
+    }
+    Ok(mmr.to_entity())
+}
+
+/// Appending entry to mmr, the peaks are merged the same way a full MMR would.
+pub fn append_time_entry(mmr: &TimeMmr, entry: &TimeEntry) -> Result<TimeMmr, Error> {
+    let leaf_count = u64::from(mmr.leaf_count());
+    let new_leaf_count = leaf_count.checked_add(1).ok_or(Error::InvalidTimeMmr)?;
+    let store = load_peaks(mmr)?;
+    let mut tree = MMR::<_, MergeTimeEntry, _>::new(mmr_size(leaf_count), &store);
+    tree.push(leaf_hash(entry))
+        .map_err(|_| Error::InvalidTimeMmr)?;
+    let size = tree.mmr_size();
+    tree.commit().map_err(|_| Error::InvalidTimeMmr)?;
+
+    let mut peaks = Byte32Vec::new_builder();
+    for pos in get_peaks(size) {
+        let peak = (&store)
+            .get_elem(pos)
+            .ok()
+            .flatten()
+            .ok_or(Error::InvalidTimeMmr)?;
+        peaks = peaks.push(Byte32::new_unchecked(peak.to_vec().into()));
+    }
+    Ok(TimeMmr::new_builder()
+        .leaf_count(new_leaf_count.into())
+        .peaks(peaks.build())
+        .build())
+}
+
+/// Root of mmr, bagging the peaks from right to left. None for an empty MMR.
+pub fn time_mmr_root(mmr: &TimeMmr) -> Result<Option<[u8; 32]>, Error> {
+    let leaf_count = u64::from(mmr.leaf_count());
+    if leaf_count == 0 {
+        return Ok(None);
+    }
+    let store = load_peaks(mmr)?;
+    MMR::<_, MergeTimeEntry, _>::new(mmr_size(leaf_count), &store)
+        .get_root()
+        .map(Some)
+        .map_err(|_| Error::InvalidTimeMmr)
+}
+
+/// Verifying proof shows its entry is the leaf at leaf_index of mmr.
+pub fn verify_time_proof(mmr: &TimeMmr, proof: TimeProofReader) -> Result<(), Error> {
+    let leaf_count = u64::from(mmr.leaf_count());
+    let leaf_index = u64::from(proof.leaf_index());
+    if leaf_index >= leaf_count {
+        return Err(Error::InvalidTimeProof);
+    }
+    let root = time_mmr_root(mmr)?.ok_or(Error::InvalidTimeProof)?;
+    let items: Vec<[u8; 32]> = proof
+        .items()
+        .iter()
+        .map(|item| {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(item.as_slice());
+            hash
+        })
+        .collect();
+    let leaf = leaf_hash(&proof.entry().to_entity());
+    match MerkleProof::<_, MergeTimeEntry>::new(mmr_size(leaf_count), items)
+        .verify(root, vec![(leaf_index_to_pos(leaf_index), leaf)])
+    {
+        Ok(true) => Ok(()),
+        _ => Err(Error::InvalidTimeProof),
+    }
+}
+
+/// Size in nodes of a MMR of leaf_count leaves.
+pub fn mmr_size(leaf_count: u64) -> u64 {
+    match leaf_count {
+        0 => 0,
+        n => leaf_index_to_mmr_size(n - 1),
+    }
+}
+
+fn load_peaks(mmr: &TimeMmr) -> Result<MemStore<[u8; 32]>, Error> {
+    // get_peaks does not support an empty MMR
+    let positions = match u64::from(mmr.leaf_count()) {
+        0 => Vec::new(),
+        leaf_count => get_peaks(mmr_size(leaf_count)),
+    };
+    if positions.len() != mmr.peaks().len() {
+        return Err(Error::InvalidTimeMmr);
+    }
+    let store = MemStore::default();
+    for (pos, peak) in positions.into_iter().zip(mmr.peaks()) {
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(peak.as_slice());
+        (&store)
+            .append(pos, vec![hash])
+            .map_err(|_| Error::InvalidTimeMmr)?;
+    }
+    Ok(store)
+}
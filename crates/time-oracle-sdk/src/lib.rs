@@ -0,0 +1,370 @@
+//! Building unsigned transactions which create and update the time cell.
+//!
+//! The returned transactions already reference the cell deps and header deps
+//! the time type script needs, callers only have to balance capacity, pay the
+//! fee and sign:
+//!
+//! ```ignore
+//! let oracle = TimeOracle::new(code_hash, ScriptHashType::Type, cell_deps);
+//! let tx = oracle.build_create_tx(&first_input, 0, lock);
+//! let tx = oracle.build_update_tx(&live_cell, &tip_header)?;
+//! ```
+//!
+//! Oracles built [`TimeOracle::with_mmr`] keep a Merkle Mountain Range of
+//! every past value in the time cell, [`TimeMmrBuilder`] replays the updates
+//! to prove any of them to a consumer.
+
+mod mmr;
+
+use std::fmt;
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, HeaderView, ScriptHashType, TransactionBuilder, TransactionView},
+    packed::{Byte32, CellDep, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use time_oracle_core::{
+    ARGS_VERSION_TIME_ARGS, SINCE_TOLERANCE, calc_type_id, parse_args_extension,
+};
+use time_oracle_reader::{append_time_entry, time_entry};
+pub use time_oracle_types::{
+    Byte32Vec, TIME_INFO_VERSION, TimeArgs, TimeArgsReader, TimeEntry, TimeInfo, TimeInfoReader,
+    TimeMmr, TimeProof, TimeProofReader,
+};
+
+pub use mmr::TimeMmrBuilder;
+
+// Flags of an absolute since whose value is a timestamp in seconds.
+const ABSOLUTE_TIMESTAMP_SINCE_FLAG: u64 = 0x4000_0000_0000_0000;
+
+/// Peaks the capacity of a time cell created with a MMR is reserved for, the
+/// cell can then be updated 2^32 - 1 times without adding capacity.
+pub const MMR_RESERVED_PEAKS: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Live cell is not typed by the time type script
+    InvalidTimeType,
+    /// Live cell data is not a TimeInfo of the supported version
+    InvalidTimeInfo,
+    /// Header does not move timestamp and block number forward
+    TimeInfoRegression,
+    /// Args require a minimum update interval but the creating block is unknown
+    CreatedHeaderMissing,
+    /// Args extension of the oracle is not valid
+    InvalidArgsExtension,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTimeType => write!(f, "cell is not a time cell"),
+            Self::InvalidTimeInfo => write!(f, "cell data is not a valid time info"),
+            Self::TimeInfoRegression => write!(f, "time info does not move forward"),
+            Self::CreatedHeaderMissing => {
+                write!(f, "block hash of the time cell is required by its args")
+            }
+            Self::InvalidArgsExtension => write!(f, "args extension is not valid"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A live time cell, as returned by an indexer.
+#[derive(Debug, Clone)]
+pub struct LiveTimeCell {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub data: Bytes,
+    /// Hash of the block which created the cell, only required when the type
+    /// args set a minimum update interval.
+    pub block_hash: Option<Byte32>,
+}
+
+/// Deployment of the checkpoint type script, whose cells copy the TimeInfo of
+/// an update and can never be spent.
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    pub code_hash: Byte32,
+    pub hash_type: ScriptHashType,
+    pub cell_dep: CellDep,
+    /// Lock of created checkpoint cells, never unlocked since the type script
+    /// rejects any spending.
+    pub lock: Script,
+}
+
+impl Checkpoint {
+    /// Type script of the checkpoints of the time cell typed by time_type.
+    pub fn type_script(&self, time_type: &Script) -> Script {
+        Script::new_builder()
+            .code_hash(self.code_hash.clone())
+            .hash_type(self.hash_type.into())
+            .args(Bytes::from(time_type.calc_script_hash().as_slice().to_vec()).pack())
+            .build()
+    }
+}
+
+/// Deployment of the time type script.
+#[derive(Debug, Clone)]
+pub struct TimeOracle {
+    pub code_hash: Byte32,
+    pub hash_type: ScriptHashType,
+    /// Cell deps of the time type script and the lock guarding time cells.
+    pub cell_deps: Vec<CellDep>,
+    /// Appended to the args of created time cells after type_id and lock hash,
+    /// empty for legacy args.
+    pub args_extension: Bytes,
+    /// Checkpoint created by every update, none by default.
+    pub checkpoint: Option<Checkpoint>,
+    /// Whether created time cells store a MMR of past values after the
+    /// TimeInfo.
+    pub mmr: bool,
+}
+
+impl TimeOracle {
+    pub fn new(code_hash: Byte32, hash_type: ScriptHashType, cell_deps: Vec<CellDep>) -> Self {
+        Self {
+            code_hash,
+            hash_type,
+            cell_deps,
+            args_extension: Bytes::new(),
+            checkpoint: None,
+            mmr: false,
+        }
+    }
+
+    /// Creating time cells which store a MMR of past values, updates always
+    /// carry the MMR of the live cell forward.
+    pub fn with_mmr(mut self) -> Self {
+        self.mmr = true;
+        self
+    }
+
+    /// Creating a checkpoint cell alongside every update.
+    pub fn with_checkpoint(mut self, checkpoint: Checkpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Creating time cells whose args carry time_args.
+    pub fn with_time_args(mut self, time_args: &TimeArgs) -> Self {
+        self.args_extension =
+            Bytes::from([[ARGS_VERSION_TIME_ARGS].as_slice(), time_args.as_slice()].concat());
+        self
+    }
+
+    /// Number of time cells created for the oracle, one unless the time args
+    /// split it into shards.
+    pub fn shard_count(&self) -> Result<u8, Error> {
+        parse_args_extension(&self.args_extension)
+            .map(|extension| extension.shard_count.max(1))
+            .map_err(|_| Error::InvalidArgsExtension)
+    }
+
+    /// Type script of the time cell created by first_input at output_index,
+    /// the first shard of a sharded oracle.
+    pub fn type_script(
+        &self,
+        first_input: &CellInput,
+        output_index: u64,
+        lock: &Script,
+    ) -> Result<Script, Error> {
+        self.shard_type_script(first_input, output_index, lock, 0)
+    }
+
+    /// Type script of shard shard_index, when the first shard is created by
+    /// first_input at output_index. All shards share the type id and are
+    /// created at consecutive outputs.
+    pub fn shard_type_script(
+        &self,
+        first_input: &CellInput,
+        output_index: u64,
+        lock: &Script,
+        shard_index: u8,
+    ) -> Result<Script, Error> {
+        let type_id = calc_type_id(first_input.as_slice(), output_index);
+        let args = [
+            type_id.as_slice(),
+            lock.calc_script_hash().as_slice(),
+            &self.shard_args_extension(shard_index)?,
+        ]
+        .concat();
+        Ok(Script::new_builder()
+            .code_hash(self.code_hash.clone())
+            .hash_type(self.hash_type.into())
+            .args(Bytes::from(args).pack())
+            .build())
+    }
+
+    fn shard_args_extension(&self, shard_index: u8) -> Result<Bytes, Error> {
+        match self.args_extension.split_first() {
+            Some((&ARGS_VERSION_TIME_ARGS, time_args)) => {
+                let time_args = TimeArgs::from_slice(time_args)
+                    .map_err(|_| Error::InvalidArgsExtension)?
+                    .as_builder()
+                    .shard_index(shard_index.into())
+                    .build();
+                Ok(Bytes::from(
+                    [[ARGS_VERSION_TIME_ARGS].as_slice(), time_args.as_slice()].concat(),
+                ))
+            }
+            _ => Ok(self.args_extension.clone()),
+        }
+    }
+
+    /// Building a transaction creating the time cell with an empty TimeInfo,
+    /// or one time cell per shard. With a MMR set, the empty TimeMmr follows
+    /// the TimeInfo and capacity is reserved for MMR_RESERVED_PEAKS peaks.
+    /// The time cells are the only outputs,
+    /// callers adding outputs must keep them starting at output_index, since
+    /// their type id commits to that index.
+    pub fn build_create_tx(
+        &self,
+        first_input: &CellInput,
+        output_index: u64,
+        lock: Script,
+    ) -> Result<TransactionView, Error> {
+        let time_info = TimeInfo::new_builder()
+            .version(TIME_INFO_VERSION.into())
+            .build();
+        let (data, reserved) = if self.mmr {
+            let data = [time_info.as_slice(), TimeMmr::default().as_slice()].concat();
+            (Bytes::from(data), MMR_RESERVED_PEAKS * 32)
+        } else {
+            (Bytes::from(time_info.as_slice().to_vec()), 0)
+        };
+        let shard_count = self.shard_count()?;
+        let outputs = (0..shard_count)
+            .map(|shard_index| {
+                let type_script =
+                    self.shard_type_script(first_input, output_index, &lock, shard_index)?;
+                Ok(CellOutput::new_builder()
+                    .lock(lock.clone())
+                    .type_(Some(type_script).pack())
+                    .build_exact_capacity(
+                        Capacity::bytes(data.len() + reserved).expect("data capacity"),
+                    )
+                    .expect("output capacity"))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let outputs_data = (0..shard_count).map(|_| data.pack());
+
+        Ok(TransactionBuilder::default()
+            .input(first_input.clone())
+            .outputs(outputs)
+            .outputs_data(outputs_data)
+            .cell_deps(self.cell_deps.clone())
+            .build())
+    }
+
+    /// Building a transaction updating live_cell to the time of header. The
+    /// new TimeInfo takes timestamp, block number and epoch from header, the
+    /// header is added to header_deps and the input since is set to the
+    /// earliest value the time type script accepts. A MMR stored by live_cell
+    /// is kept with the new entry appended. With a checkpoint set, a
+    /// checkpoint cell carrying the new data follows the time cell.
+    pub fn build_update_tx(
+        &self,
+        live_cell: &LiveTimeCell,
+        header: &HeaderView,
+    ) -> Result<TransactionView, Error> {
+        let type_script = live_cell
+            .output
+            .type_()
+            .to_opt()
+            .filter(|script| {
+                script.code_hash() == self.code_hash && script.hash_type() == self.hash_type.into()
+            })
+            .ok_or(Error::InvalidTimeType)?;
+        let (old_info, old_mmr) = parse_time_data(&live_cell.data)?;
+
+        let timestamp = header.timestamp();
+        if timestamp <= u64::from(old_info.timestamp())
+            || header.number() <= u64::from(old_info.block_number())
+        {
+            return Err(Error::TimeInfoRegression);
+        }
+        let new_info = TimeInfo::new_builder()
+            .version(TIME_INFO_VERSION.into())
+            .timestamp(timestamp.into())
+            .block_number(header.number().into())
+            .epoch(header.epoch().full_value().into())
+            .sequence((u64::from(old_info.sequence()) + 1).into())
+            .build();
+
+        let mut header_deps = vec![header.hash()];
+        let args: Bytes = type_script.args().unpack();
+        let extension = parse_args_extension(args.get(64..).unwrap_or_default())
+            .map_err(|_| Error::InvalidArgsExtension)?;
+        if extension.min_update_interval > 0 {
+            header_deps.push(
+                live_cell
+                    .block_hash
+                    .clone()
+                    .ok_or(Error::CreatedHeaderMissing)?,
+            );
+        }
+
+        let since_seconds = timestamp.saturating_sub(SINCE_TOLERANCE).div_ceil(1000);
+        let input = CellInput::new_builder()
+            .previous_output(live_cell.out_point.clone())
+            .since((ABSOLUTE_TIMESTAMP_SINCE_FLAG | since_seconds).pack())
+            .build();
+
+        let data = match old_mmr {
+            Some(old_mmr) => {
+                let new_mmr = append_time_entry(&old_mmr, &time_entry(&new_info))
+                    .map_err(|_| Error::InvalidTimeInfo)?;
+                Bytes::from([new_info.as_slice(), new_mmr.as_slice()].concat())
+            }
+            None => Bytes::from(new_info.as_slice().to_vec()),
+        };
+        let mut builder = TransactionBuilder::default()
+            .input(input)
+            .output(live_cell.output.clone())
+            .output_data(data.pack())
+            .cell_deps(self.cell_deps.clone())
+            .header_deps(header_deps);
+        if let Some(checkpoint) = &self.checkpoint {
+            let output = CellOutput::new_builder()
+                .lock(checkpoint.lock.clone())
+                .type_(Some(checkpoint.type_script(&type_script)).pack())
+                .build_exact_capacity(Capacity::bytes(data.len()).expect("data capacity"))
+                .expect("output capacity");
+            builder = builder
+                .output(output)
+                .output_data(data.pack())
+                .cell_dep(checkpoint.cell_dep.clone());
+        }
+        Ok(builder.build())
+    }
+}
+
+/// Parsing cell data as one molecule encoded TimeInfo of the supported version,
+/// optionally followed by a TimeMmr.
+pub fn parse_time_info(data: &[u8]) -> Result<TimeInfo, Error> {
+    parse_time_data(data).map(|(time_info, _)| time_info)
+}
+
+/// Parsing the TimeMmr stored after the TimeInfo in cell data, none when the
+/// time cell stores no MMR.
+pub fn parse_time_mmr(data: &[u8]) -> Result<Option<TimeMmr>, Error> {
+    parse_time_data(data).map(|(_, mmr)| mmr)
+}
+
+fn parse_time_data(data: &[u8]) -> Result<(TimeInfo, Option<TimeMmr>), Error> {
+    let (time_info, mmr) = data
+        .split_at_checked(TimeInfo::TOTAL_SIZE)
+        .ok_or(Error::InvalidTimeInfo)?;
+    let reader = TimeInfoReader::from_slice(time_info).map_err(|_| Error::InvalidTimeInfo)?;
+    if u8::from(reader.version()) != TIME_INFO_VERSION {
+        return Err(Error::InvalidTimeInfo);
+    }
+    let mmr = match mmr {
+        [] => None,
+        mmr => Some(time_oracle_reader::parse_time_mmr(mmr).map_err(|_| Error::InvalidTimeInfo)?),
+    };
+    Ok((reader.to_entity(), mmr))
+}
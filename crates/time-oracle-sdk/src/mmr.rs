@@ -0,0 +1,70 @@
+//! Rebuilding the full MMR of a time cell off chain to prove past values.
+
+use ckb_merkle_mountain_range::{MMRStore, helper::get_peaks, leaf_index_to_pos, util::MemMMR};
+use ckb_types::{bytes::Bytes, prelude::*};
+use time_oracle_reader::mmr::{MergeTimeEntry, leaf_hash};
+use time_oracle_types::{Byte32, Byte32Vec, TimeEntry, TimeInfo, TimeMmr, TimeProof};
+
+/// Full MMR of the entries appended by the updates of a time cell, built by
+/// replaying them in order, e.g. from checkpoints or the time cell history.
+#[derive(Default)]
+pub struct TimeMmrBuilder {
+    entries: Vec<TimeEntry>,
+    mmr: MemMMR<[u8; 32], MergeTimeEntry>,
+}
+
+impl TimeMmrBuilder {
+    /// Appending the entry of the TimeInfo set by the next update.
+    pub fn push(&mut self, time_info: &TimeInfo) {
+        self.push_entry(time_oracle_reader::time_entry(time_info));
+    }
+
+    /// Appending entry as the next leaf.
+    pub fn push_entry(&mut self, entry: TimeEntry) {
+        self.mmr.push(leaf_hash(&entry)).expect("push leaf");
+        self.entries.push(entry);
+    }
+
+    /// Number of appended entries.
+    pub fn leaf_count(&self) -> u64 {
+        self.entries.len() as u64
+    }
+
+    /// The TimeMmr a time cell stores after the appended updates.
+    pub fn time_mmr(&self) -> TimeMmr {
+        let peaks = match self.mmr.mmr_size() {
+            0 => Vec::new(),
+            size => get_peaks(size),
+        };
+        let peaks = peaks.into_iter().map(|pos| {
+            let peak = self.mmr.store().get_elem(pos).expect("peak").expect("peak");
+            byte32(&peak)
+        });
+        TimeMmr::new_builder()
+            .leaf_count(self.leaf_count().into())
+            .peaks(Byte32Vec::new_builder().extend(peaks).build())
+            .build()
+    }
+
+    /// Proof of the entry at leaf_index against the current MMR, none when no
+    /// such entry was appended.
+    pub fn proof(&self, leaf_index: u64) -> Option<TimeProof> {
+        let entry = self.entries.get(usize::try_from(leaf_index).ok()?)?;
+        let proof = self
+            .mmr
+            .gen_proof(vec![leaf_index_to_pos(leaf_index)])
+            .expect("proof");
+        let items = proof.proof_items().iter().map(byte32);
+        Some(
+            TimeProof::new_builder()
+                .leaf_index(leaf_index.into())
+                .entry(entry.clone())
+                .items(Byte32Vec::new_builder().extend(items).build())
+                .build(),
+        )
+    }
+}
+
+fn byte32(hash: &[u8; 32]) -> Byte32 {
+    Byte32::new_unchecked(Bytes::from(hash.to_vec()))
+}
@@ -0,0 +1,42 @@
+#![no_std]
+
+// Molecule definitions shared by the time contract and everyone reading or
+// writing the time cell. `time_info.rs`, `time_args.rs` and `time_mmr.rs` are
+// generated from `schemas/*.mol` with moleculec 0.8, do not edit them by hand.
+#[allow(clippy::all)]
+pub mod time_args;
+#[allow(clippy::all)]
+pub mod time_info;
+#[allow(clippy::all)]
+pub mod time_mmr;
+
+pub use time_args::*;
+pub use time_info::*;
+pub use time_mmr::*;
+
+use molecule::prelude::*;
+
+/// The only TimeInfo layout version accepted by the time contract.
+pub const TIME_INFO_VERSION: u8 = 1;
+
+impl From<Uint64Reader<'_>> for u64 {
+    fn from(value: Uint64Reader<'_>) -> Self {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(value.raw_data());
+        u64::from_le_bytes(buf)
+    }
+}
+
+impl From<Uint64> for u64 {
+    fn from(value: Uint64) -> Self {
+        value.as_reader().into()
+    }
+}
+
+impl From<u64> for Uint64 {
+    fn from(value: u64) -> Self {
+        Uint64::new_builder()
+            .set(value.to_le_bytes().map(Byte::new))
+            .build()
+    }
+}
@@ -0,0 +1,81 @@
+use std::fs;
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ckb_jsonrpc_types as json;
+use serde::Deserialize;
+
+use crate::error::Error;
+use crate::keeper::KeeperConfig;
+
+/// Keeper configuration file, see keeper.example.toml.
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    pub rpc_url: String,
+    /// Seconds of chain time after which the oracle is refreshed.
+    #[serde(default = "default_update_interval")]
+    pub update_interval: u64,
+    /// Seconds between two checks of the live time cell.
+    #[serde(default = "default_poll_interval")]
+    pub poll_interval: u64,
+    /// Seconds before retrying an update which lost the race to another
+    /// keeper, doubled on each further loss up to max_retry_delay.
+    #[serde(default = "default_retry_delay")]
+    pub retry_delay: u64,
+    #[serde(default = "default_max_retry_delay")]
+    pub max_retry_delay: u64,
+    pub time_type: json::Script,
+    pub cell_deps: Vec<json::CellDep>,
+    pub wallet: WalletConfig,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WalletConfig {
+    /// Cell dep of the secp256k1_blake160 dep group.
+    pub cell_dep: json::CellDep,
+    /// Fee in shannons paid by each update.
+    pub fee: u64,
+}
+
+fn default_update_interval() -> u64 {
+    10 * 60
+}
+
+fn default_poll_interval() -> u64 {
+    30
+}
+
+fn default_retry_delay() -> u64 {
+    10
+}
+
+fn default_max_retry_delay() -> u64 {
+    5 * 60
+}
+
+impl Config {
+    pub fn load(path: &str) -> Result<Self, Error> {
+        let content = fs::read_to_string(path).map_err(|err| Error::Config(err.to_string()))?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, Error> {
+        toml::from_str(content).map_err(|err| Error::Config(err.to_string()))
+    }
+
+    pub fn keeper(&self) -> KeeperConfig {
+        KeeperConfig {
+            time_type: self.time_type.clone().into(),
+            cell_deps: self.cell_deps.iter().cloned().map(Into::into).collect(),
+            update_interval: self.update_interval * 1000,
+            poll_interval: Duration::from_secs(self.poll_interval),
+            retry_delay: Duration::from_secs(self.retry_delay),
+            max_retry_delay: Duration::from_secs(self.max_retry_delay),
+            jitter_seed: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|now| now.as_nanos() as u64)
+                .unwrap_or_default()
+                ^ u64::from(process::id()),
+        }
+    }
+}
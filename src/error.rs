@@ -0,0 +1,49 @@
+use std::fmt;
+
+use crate::rpc::RpcError;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Keeper configuration could not be loaded
+    Config(String),
+    /// Request to the CKB node failed
+    Rpc(RpcError),
+    /// Update transaction could not be built
+    Sdk(time_oracle_sdk::Error),
+    /// Indexer returns no live time cell, or more than one
+    TimeCellMissing,
+    /// Wallet has no cell large enough to pay the fee and keep change
+    FeeCellMissing,
+    /// Wallet failed to sign the transaction
+    Sign(String),
+    /// Command line arguments or files passed to inspect are malformed
+    Input(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Config(message) => write!(f, "invalid config: {}", message),
+            Self::Rpc(err) => err.fmt(f),
+            Self::Sdk(err) => err.fmt(f),
+            Self::TimeCellMissing => write!(f, "live time cell is missing"),
+            Self::FeeCellMissing => write!(f, "no wallet cell can pay the fee"),
+            Self::Sign(message) => write!(f, "failed to sign: {}", message),
+            Self::Input(message) => write!(f, "invalid input: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<RpcError> for Error {
+    fn from(err: RpcError) -> Self {
+        Self::Rpc(err)
+    }
+}
+
+impl From<time_oracle_sdk::Error> for Error {
+    fn from(err: time_oracle_sdk::Error) -> Self {
+        Self::Sdk(err)
+    }
+}
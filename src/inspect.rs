@@ -0,0 +1,233 @@
+//! `inspect` subcommand decoding time type args, time cell data and mock
+//! transactions dumped by ckb-testtool.
+//!
+//! ```text
+//! ckb-time-oracle inspect args 0x7ac2...
+//! ckb-time-oracle inspect data 0x01...
+//! ckb-time-oracle inspect tx failed_txs/0x...json [code_hash]
+//! ```
+
+use std::fmt::Write;
+use std::fs;
+
+use ckb_mock_tx_types::{MockTransaction, ReprMockTransaction};
+use ckb_types::{
+    core::EpochNumberWithFraction,
+    packed::{Byte32, Script},
+    prelude::*,
+};
+use time_oracle_core::parse_args_extension;
+use time_oracle_error::Error as RuleError;
+use time_oracle_sdk::parse_time_info;
+
+use crate::error::Error;
+use crate::rules::{self, ResolvedInput};
+
+const USAGE: &str =
+    "usage: inspect args <hex> | inspect data <hex> | inspect tx <mock_tx.json> [code_hash]";
+// Bytes of type_id and lock hash at the beginning of the time type args.
+const ARGS_PREFIX_LENGTH: usize = 64;
+
+pub fn run(args: &[String]) -> Result<String, Error> {
+    match args {
+        [kind, hex] if kind == "args" => Ok(inspect_args(&decode_hex(hex)?)),
+        [kind, hex] if kind == "data" => Ok(inspect_data(&decode_hex(hex)?)),
+        [kind, path] if kind == "tx" => inspect_tx(&read(path)?, None),
+        [kind, path, code_hash] if kind == "tx" => {
+            let code_hash = decode_hex(code_hash)?;
+            let code_hash = Byte32::from_slice(&code_hash).map_err(|_| {
+                Error::Input(format!("code hash {} is not 32 bytes", hex(&code_hash)))
+            })?;
+            inspect_tx(&read(path)?, Some(code_hash))
+        }
+        _ => Err(Error::Input(USAGE.to_string())),
+    }
+}
+
+/// Splitting the time type args into type_id, lock hash and the args
+/// extension.
+pub fn inspect_args(args: &[u8]) -> String {
+    if args.len() < ARGS_PREFIX_LENGTH {
+        return format!(
+            "error: {}: args are {} bytes, at least {} expected\n",
+            rule_error(RuleError::ArgsLengthNotEnough),
+            args.len(),
+            ARGS_PREFIX_LENGTH
+        );
+    }
+    let mut report = String::new();
+    writeln!(report, "type_id: {}", hex(&args[..32])).unwrap();
+    writeln!(report, "lock_hash: {}", hex(&args[32..64])).unwrap();
+    match parse_args_extension(&args[64..]) {
+        Ok(extension) => {
+            writeln!(
+                report,
+                "header_dep_window: {} ms",
+                extension.header_dep_window()
+            )
+            .unwrap();
+            writeln!(
+                report,
+                "min_update_interval: {} ms",
+                extension.min_update_interval
+            )
+            .unwrap();
+            writeln!(report, "min_fee: {} shannons", extension.min_fee).unwrap();
+            for keeper in extension.keepers {
+                writeln!(report, "keeper: {}", hex(&keeper)).unwrap();
+            }
+            if extension.shard_count > 0 {
+                writeln!(
+                    report,
+                    "shard: {} of {}",
+                    extension.shard_index, extension.shard_count
+                )
+                .unwrap();
+            }
+        }
+        Err(err) => writeln!(report, "error: {}: {}", rule_error(err), err).unwrap(),
+    }
+    report
+}
+
+/// Decoding the TimeInfo stored in the data of a time cell.
+pub fn inspect_data(data: &[u8]) -> String {
+    let time_info = match parse_time_info(data) {
+        Ok(time_info) => time_info,
+        Err(err) => {
+            return format!(
+                "error: {}: {}\n",
+                rule_error(RuleError::InvalidTimeInfo),
+                err
+            );
+        }
+    };
+    let epoch = EpochNumberWithFraction::from_full_value(time_info.epoch().into());
+    let mut report = String::new();
+    writeln!(report, "version: {}", u8::from(time_info.version())).unwrap();
+    writeln!(report, "timestamp: {}", u64::from(time_info.timestamp())).unwrap();
+    writeln!(
+        report,
+        "block_number: {}",
+        u64::from(time_info.block_number())
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "epoch: {} ({}/{})",
+        epoch.number(),
+        epoch.index(),
+        epoch.length()
+    )
+    .unwrap();
+    writeln!(report, "sequence: {}", u64::from(time_info.sequence())).unwrap();
+    report
+}
+
+/// Decoding every time cell of a mock transaction, and running the rules of
+/// the time type script against it. Without code_hash, every type script
+/// whose args are long enough to hold type_id and lock hash is treated as a
+/// time type script.
+pub fn inspect_tx(json: &str, code_hash: Option<Byte32>) -> Result<String, Error> {
+    let repr: ReprMockTransaction =
+        serde_json::from_str(json).map_err(|err| Error::Input(err.to_string()))?;
+    let mock_tx = MockTransaction::from(repr);
+    let tx = mock_tx.core_transaction();
+    let header_deps: Vec<_> = tx
+        .header_deps_iter()
+        .filter_map(|hash| {
+            mock_tx
+                .mock_info
+                .header_deps
+                .iter()
+                .find(|header| header.hash() == hash)
+                .cloned()
+        })
+        .collect();
+    let mut inputs = Vec::new();
+    for out_point in tx.input_pts_iter() {
+        let input = mock_tx
+            .mock_info
+            .inputs
+            .iter()
+            .find(|input| input.input.previous_output() == out_point)
+            .ok_or_else(|| Error::Input(format!("input {} is not in mock_info", out_point)))?;
+        inputs.push(ResolvedInput {
+            output: input.output.clone(),
+            data: input.data.clone(),
+            header: input.header.as_ref().and_then(|hash| {
+                mock_tx
+                    .mock_info
+                    .header_deps
+                    .iter()
+                    .find(|header| &header.hash() == hash)
+                    .cloned()
+            }),
+        });
+    }
+
+    let is_time_script = |script: &Script| match &code_hash {
+        Some(code_hash) => &script.code_hash() == code_hash,
+        None => script.args().raw_data().len() >= ARGS_PREFIX_LENGTH,
+    };
+    let scripts = rules::time_scripts(&tx, &inputs, is_time_script);
+    if scripts.is_empty() {
+        return Err(Error::Input(format!(
+            "no time type script in tx {}",
+            tx.hash()
+        )));
+    }
+
+    let mut report = String::new();
+    for script in scripts {
+        writeln!(report, "type script {}", script.calc_script_hash()).unwrap();
+        report.push_str(&indent(&inspect_args(&script.args().raw_data())));
+        let cells = inputs
+            .iter()
+            .map(|input| ("input", input.output.clone(), input.data.clone()))
+            .enumerate()
+            .chain(
+                tx.outputs_with_data_iter()
+                    .map(|(output, data)| ("output", output, data))
+                    .enumerate(),
+            );
+        for (index, (source, output, data)) in cells {
+            if output.type_().to_opt().as_ref() == Some(&script) {
+                writeln!(report, "  {} {}:", source, index).unwrap();
+                report.push_str(&indent(&indent(&inspect_data(&data))));
+            }
+        }
+        match rules::verify_time_type(&tx, &inputs, &header_deps, &script) {
+            Ok(()) => writeln!(report, "  result: ok").unwrap(),
+            Err(err) => writeln!(report, "  result: {}: {}", rule_error(err), err).unwrap(),
+        }
+    }
+    Ok(report)
+}
+
+// Naming an error of the time type script together with its exit code.
+fn rule_error(err: RuleError) -> String {
+    format!("{:?} ({})", err, err as i8)
+}
+
+fn indent(text: &str) -> String {
+    text.lines().map(|line| format!("  {}\n", line)).collect()
+}
+
+fn read(path: &str) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|err| Error::Input(format!("{}: {}", path, err)))
+}
+
+fn decode_hex(text: &str) -> Result<Vec<u8>, Error> {
+    let text = text.trim_start_matches("0x");
+    let mut bytes = vec![0; text.len() / 2];
+    if !text.is_empty() {
+        faster_hex::hex_decode(text.as_bytes(), &mut bytes)
+            .map_err(|_| Error::Input(format!("{} is not a hex string", text)))?;
+    }
+    Ok(bytes)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    format!("0x{}", faster_hex::hex_string(bytes))
+}
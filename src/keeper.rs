@@ -0,0 +1,207 @@
+use std::thread;
+use std::time::Duration;
+
+use ckb_types::{
+    core::{HeaderView, ScriptHashType},
+    packed::{Byte32, CellDep, Script},
+};
+use time_oracle_sdk::{LiveTimeCell, TimeOracle, parse_time_info};
+
+use crate::error::Error;
+use crate::rpc::{CkbRpc, ScriptType};
+use crate::wallet::Wallet;
+
+#[derive(Clone)]
+pub struct KeeperConfig {
+    /// Type script of the time cell to keep fresh.
+    pub time_type: Script,
+    /// Cell deps of the time type script and the lock of the time cell.
+    pub cell_deps: Vec<CellDep>,
+    /// Milliseconds of chain time after which the oracle is refreshed.
+    pub update_interval: u64,
+    /// How often the live time cell is checked.
+    pub poll_interval: Duration,
+    /// Delay before the first retry of an update which lost the race, doubled
+    /// on each further loss up to max_retry_delay.
+    pub retry_delay: Duration,
+    pub max_retry_delay: Duration,
+    /// Seed of the jitter added to retry delays.
+    pub jitter_seed: u64,
+}
+
+/// Result of checking the oracle once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The oracle is younger than the update interval
+    Fresh,
+    /// An update transaction was accepted by the node
+    Updated(Byte32),
+    /// Another keeper spent the time cell first, and the oracle still needs an
+    /// update
+    Lost { retry_after: Duration },
+}
+
+/// Exponential backoff with jitter between retries of a lost update. Half of
+/// each delay is random, so that keepers losing the same race spread out.
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    attempt: u32,
+    state: u64,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration, seed: u64) -> Self {
+        Self {
+            base,
+            max,
+            attempt: 0,
+            state: seed,
+        }
+    }
+
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self
+            .base
+            .saturating_mul(1 << self.attempt.min(16))
+            .min(self.max);
+        self.attempt += 1;
+        let half = delay.as_millis() as u64 / 2;
+        Duration::from_millis(delay.as_millis() as u64 - half + self.next_random() % (half + 1))
+    }
+
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    // splitmix64
+    fn next_random(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+pub struct Keeper<R, W> {
+    config: KeeperConfig,
+    oracle: TimeOracle,
+    rpc: R,
+    wallet: W,
+    backoff: Backoff,
+}
+
+impl<R: CkbRpc, W: Wallet> Keeper<R, W> {
+    pub fn new(config: KeeperConfig, rpc: R, wallet: W) -> Result<Self, Error> {
+        let hash_type = ScriptHashType::try_from(config.time_type.hash_type())
+            .map_err(|err| Error::Config(err.to_string()))?;
+        let oracle = TimeOracle::new(
+            config.time_type.code_hash(),
+            hash_type,
+            config.cell_deps.clone(),
+        );
+        let backoff = Backoff::new(
+            config.retry_delay,
+            config.max_retry_delay,
+            config.jitter_seed,
+        );
+        Ok(Self {
+            config,
+            oracle,
+            rpc,
+            wallet,
+            backoff,
+        })
+    }
+
+    /// Resolving the live time cell through the indexer, together with the hash
+    /// of the block which created it.
+    pub fn load_live_time_cell(&self) -> Result<LiveTimeCell, Error> {
+        let mut cells = self
+            .rpc
+            .get_cells(&self.config.time_type, ScriptType::Type, 2)?;
+        if cells.len() != 1 {
+            return Err(Error::TimeCellMissing);
+        }
+        let cell = cells.remove(0);
+        let block_hash = self
+            .rpc
+            .get_header_by_number(cell.block_number)?
+            .map(|header| header.hash());
+        Ok(LiveTimeCell {
+            out_point: cell.out_point,
+            output: cell.output,
+            data: cell.data,
+            block_hash,
+        })
+    }
+
+    fn needs_update(&self, tip: &HeaderView, live_cell: &LiveTimeCell) -> Result<bool, Error> {
+        let time_info = parse_time_info(&live_cell.data)?;
+        let timestamp = u64::from(time_info.timestamp());
+        Ok(tip.timestamp() >= timestamp.saturating_add(self.config.update_interval))
+    }
+
+    /// Checking the oracle once, and submitting an update built on the tip
+    /// header when the oracle is older than the update interval. When another
+    /// keeper spent the time cell first, the new live time cell is resolved to
+    /// decide whether an update is still needed.
+    pub fn tick(&mut self) -> Result<Outcome, Error> {
+        let tip = self.rpc.get_tip_header()?;
+        let live_cell = self.load_live_time_cell()?;
+        if !self.needs_update(&tip, &live_cell)? {
+            self.backoff.reset();
+            return Ok(Outcome::Fresh);
+        }
+
+        let tx = self.oracle.build_update_tx(&live_cell, &tip)?;
+        let tx = self.wallet.complete_tx(&self.rpc, tx)?;
+        match self.rpc.send_transaction(&tx) {
+            Ok(hash) => {
+                self.backoff.reset();
+                Ok(Outcome::Updated(hash))
+            }
+            Err(err) if err.is_input_spent() => {
+                let tip = self.rpc.get_tip_header()?;
+                let live_cell = self.load_live_time_cell()?;
+                if self.needs_update(&tip, &live_cell)? {
+                    Ok(Outcome::Lost {
+                        retry_after: self.backoff.next_delay(),
+                    })
+                } else {
+                    self.backoff.reset();
+                    Ok(Outcome::Fresh)
+                }
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// How long to wait after a tick before the next one.
+    pub fn delay_after(&self, result: &Result<Outcome, Error>) -> Duration {
+        match result {
+            Ok(Outcome::Lost { retry_after }) => *retry_after,
+            _ => self.config.poll_interval,
+        }
+    }
+
+    /// Checking the oracle every poll interval, forever.
+    pub fn run(&mut self) -> ! {
+        loop {
+            let result = self.tick();
+            match &result {
+                Ok(Outcome::Fresh) => {}
+                Ok(Outcome::Updated(hash)) => println!("time oracle updated in tx {}", hash),
+                Ok(Outcome::Lost { retry_after }) => {
+                    println!(
+                        "time cell spent by another keeper, retry in {:?}",
+                        retry_after
+                    )
+                }
+                Err(err) => eprintln!("failed to update time oracle: {}", err),
+            }
+            thread::sleep(self.delay_after(&result));
+        }
+    }
+}
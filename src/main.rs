@@ -1,3 +1,77 @@
+//! Keeper service refreshing the time oracle.
+//!
+//! The keeper watches the live time cell and, once its timestamp is older than
+//! the configured update interval, submits an update built on the tip header.
+//! The fee is paid from a secp256k1 wallet whose private key is read from the
+//! KEEPER_PRIVATE_KEY environment variable.
+//!
+//! ```text
+//! KEEPER_PRIVATE_KEY=0x... ckb-time-oracle keeper.toml
+//! ```
+//!
+//! `ckb-time-oracle inspect` decodes time cells instead, see the inspect
+//! module.
+
+mod config;
+mod error;
+mod inspect;
+mod keeper;
+#[cfg(test)]
+mod mock;
+mod rpc;
+mod rules;
+mod wallet;
+
+#[cfg(test)]
+mod tests;
+
+use std::env;
+use std::process;
+
+use ckb_crypto::secp::Privkey;
+use ckb_types::H256;
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::keeper::Keeper;
+use crate::rpc::HttpRpc;
+use crate::wallet::Secp256k1Wallet;
+
+fn load_privkey() -> Result<Privkey, Error> {
+    let key = env::var("KEEPER_PRIVATE_KEY")
+        .map_err(|_| Error::Config("KEEPER_PRIVATE_KEY is not set".to_string()))?;
+    let key: H256 = key
+        .trim_start_matches("0x")
+        .parse()
+        .map_err(|_| Error::Config("KEEPER_PRIVATE_KEY is not a 32 byte hex".to_string()))?;
+    Ok(Privkey::from(key))
+}
+
+fn start() -> Result<(), Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("inspect") => {
+            print!("{}", inspect::run(&args[1..])?);
+            Ok(())
+        }
+        path => start_keeper(path.unwrap_or("keeper.toml")),
+    }
+}
+
+fn start_keeper(path: &str) -> Result<(), Error> {
+    let config = Config::load(path)?;
+    let wallet = Secp256k1Wallet::new(
+        load_privkey()?,
+        config.wallet.cell_dep.clone().into(),
+        config.wallet.fee,
+    )?;
+    let mut keeper = Keeper::new(config.keeper(), HttpRpc::new(&config.rpc_url), wallet)?;
+    keeper.run()
+}
+
 fn main() {
-    println!("Hello, world!");
+    if let Err(err) = start() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,322 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{EpochNumberWithFraction, HeaderBuilder, HeaderView, TransactionView},
+    packed::{Byte32, CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use time_oracle_sdk::parse_time_info;
+
+use crate::error::Error;
+use crate::keeper::{Keeper, Outcome};
+use crate::rpc::{CkbRpc, LiveCell, RpcError, ScriptType};
+use crate::rules::{self, ResolvedInput};
+use crate::wallet::Wallet;
+
+// Milliseconds between a block and the block committing a transaction.
+pub const BLOCK_INTERVAL: u64 = 8_000;
+const EPOCH_LENGTH: u64 = 1800;
+
+/// Deterministic in-memory chain. Accepted transactions wait in the pool until
+/// the next block, and transactions touching cells of the time type script are
+/// checked against the rules in time-oracle-core.
+pub struct MockChain {
+    time_code_hash: Byte32,
+    headers: Vec<HeaderView>,
+    cells: Vec<LiveCell>,
+    pool: Vec<(TransactionView, Vec<LiveCell>)>,
+    pub committed: Vec<TransactionView>,
+}
+
+impl MockChain {
+    pub fn new(time_code_hash: Byte32, timestamp: u64) -> Self {
+        Self {
+            time_code_hash,
+            headers: vec![header(0, timestamp, Byte32::default())],
+            cells: Vec::new(),
+            pool: Vec::new(),
+            committed: Vec::new(),
+        }
+    }
+
+    pub fn tip(&self) -> HeaderView {
+        self.headers.last().cloned().expect("genesis")
+    }
+
+    /// Mining a block ms after the tip, committing every transaction in the
+    /// pool.
+    pub fn advance(&mut self, ms: u64) {
+        let tip = self.tip();
+        self.headers
+            .push(header(tip.number() + 1, tip.timestamp() + ms, tip.hash()));
+        let block_number = tip.number() + 1;
+        for (tx, inputs) in std::mem::take(&mut self.pool) {
+            self.cells
+                .retain(|cell| !inputs.iter().any(|input| input.out_point == cell.out_point));
+            for (index, (output, data)) in tx.outputs_with_data_iter().enumerate() {
+                self.cells.push(LiveCell {
+                    out_point: OutPoint::new(tx.hash(), index as u32),
+                    output,
+                    data,
+                    block_number,
+                });
+            }
+            self.committed.push(tx);
+        }
+    }
+
+    /// Creating a live cell committed in the tip block.
+    pub fn create_cell(&mut self, output: CellOutput, data: Bytes) -> OutPoint {
+        let out_point = OutPoint::new(Byte32::new([self.cells.len() as u8 + 1; 32]), 0);
+        self.cells.push(LiveCell {
+            out_point: out_point.clone(),
+            output,
+            data,
+            block_number: self.tip().number(),
+        });
+        out_point
+    }
+
+    fn header_by_hash(&self, hash: &Byte32) -> Option<&HeaderView> {
+        self.headers.iter().find(|header| &header.hash() == hash)
+    }
+
+    fn verify_time_type(
+        &self,
+        tx: &TransactionView,
+        inputs: &[ResolvedInput],
+        script: &Script,
+    ) -> Result<(), RpcError> {
+        let mut header_deps = Vec::new();
+        for hash in tx.header_deps_iter() {
+            let header = self.header_by_hash(&hash).ok_or(RpcError::Rejected {
+                code: -301,
+                message: format!(
+                    "TransactionFailedToResolve: Resolve failed Unknown({})",
+                    hash
+                ),
+            })?;
+            header_deps.push(header.clone());
+        }
+        rules::verify_time_type(tx, inputs, &header_deps, script).map_err(|err| {
+            RpcError::Rejected {
+                code: -302,
+                message: format!(
+                    "TransactionFailedToVerify: Verification failed Script(TransactionScriptError {{ source: Inputs[0].Type, cause: ValidationFailure: see error code {} on page https://nervosnetwork.github.io/ckb-script-error-codes/by-type-hash/{:x}.html#{} }})",
+                    err as i8,
+                    script.code_hash(),
+                    err as i8
+                ),
+            }
+        })
+    }
+
+    /// Live cell of the time type script.
+    pub fn time_cell(&self) -> Option<&LiveCell> {
+        self.cells.iter().find(|cell| {
+            cell.output
+                .type_()
+                .to_opt()
+                .is_some_and(|script| script.code_hash() == self.time_code_hash)
+        })
+    }
+
+    /// Accepting tx into the pool once all its inputs are live, none of them
+    /// is spent by another pool transaction, and the time type script accepts
+    /// it against the tip.
+    pub fn submit(&mut self, tx: &TransactionView) -> Result<Byte32, RpcError> {
+        let mut inputs = Vec::new();
+        for out_point in tx.input_pts_iter() {
+            if self
+                .pool
+                .iter()
+                .any(|(pending, _)| pending.input_pts_iter().any(|spent| spent == out_point))
+            {
+                return Err(RpcError::Rejected {
+                    code: -1111,
+                    message: format!(
+                        "PoolRejectedRBF: RBF rejected: input {} is already spent by a pool transaction",
+                        out_point
+                    ),
+                });
+            }
+            let cell = self
+                .cells
+                .iter()
+                .find(|cell| cell.out_point == out_point)
+                .ok_or(RpcError::Rejected {
+                    code: -301,
+                    message: format!(
+                        "TransactionFailedToResolve: Resolve failed Dead({})",
+                        out_point
+                    ),
+                })?;
+            inputs.push(cell.clone());
+        }
+
+        let resolved: Vec<_> = inputs
+            .iter()
+            .map(|cell| ResolvedInput {
+                output: cell.output.clone(),
+                data: cell.data.clone(),
+                header: Some(self.headers[cell.block_number as usize].clone()),
+            })
+            .collect();
+        let time_scripts = rules::time_scripts(tx, &resolved, |script| {
+            script.code_hash() == self.time_code_hash
+        });
+        for script in time_scripts {
+            self.verify_time_type(tx, &resolved, &script)?;
+        }
+
+        self.pool.push((tx.clone(), inputs));
+        Ok(tx.hash())
+    }
+}
+
+fn header(number: u64, timestamp: u64, parent_hash: Byte32) -> HeaderView {
+    HeaderBuilder::default()
+        .parent_hash(parent_hash)
+        .number(number.pack())
+        .epoch(
+            EpochNumberWithFraction::new(
+                number / EPOCH_LENGTH,
+                number % EPOCH_LENGTH,
+                EPOCH_LENGTH,
+            )
+            .pack(),
+        )
+        .timestamp(timestamp.pack())
+        .compact_target(1u32.pack())
+        .build()
+}
+
+/// CkbRpc backed by a MockChain, clones share the same chain.
+#[derive(Clone)]
+pub struct MockRpc(pub Rc<RefCell<MockChain>>);
+
+impl MockRpc {
+    pub fn new(chain: MockChain) -> Self {
+        Self(Rc::new(RefCell::new(chain)))
+    }
+}
+
+impl CkbRpc for MockRpc {
+    fn get_tip_header(&self) -> Result<HeaderView, RpcError> {
+        Ok(self.0.borrow().tip())
+    }
+
+    fn get_header_by_number(&self, number: u64) -> Result<Option<HeaderView>, RpcError> {
+        Ok(self.0.borrow().headers.get(number as usize).cloned())
+    }
+
+    fn get_cells(
+        &self,
+        script: &Script,
+        script_type: ScriptType,
+        limit: u32,
+    ) -> Result<Vec<LiveCell>, RpcError> {
+        Ok(self
+            .0
+            .borrow()
+            .cells
+            .iter()
+            .filter(|cell| match script_type {
+                ScriptType::Lock => &cell.output.lock() == script,
+                ScriptType::Type => cell.output.type_().to_opt().as_ref() == Some(script),
+            })
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    fn send_transaction(&self, tx: &TransactionView) -> Result<Byte32, RpcError> {
+        self.0.borrow_mut().submit(tx)
+    }
+}
+
+/// Wallet leaving the transaction untouched, the mock chain charges no fee.
+pub struct NoFee;
+
+impl Wallet for NoFee {
+    fn complete_tx<R: CkbRpc>(
+        &self,
+        _rpc: &R,
+        tx: TransactionView,
+    ) -> Result<TransactionView, Error> {
+        Ok(tx)
+    }
+}
+
+/// Tally of the keeper ticks in a Simulation.
+#[derive(Debug, Default)]
+pub struct SimulationStats {
+    pub updated: usize,
+    pub lost: usize,
+    pub errors: usize,
+    /// Largest gap in milliseconds between a block and the oracle timestamp.
+    pub max_staleness: u64,
+}
+
+/// Several keepers sharing one MockChain, each waking up on its own schedule
+/// while a block is mined every BLOCK_INTERVAL.
+pub struct Simulation {
+    rpc: MockRpc,
+    // keepers with the chain time at which they tick next
+    keepers: Vec<(Keeper<MockRpc, NoFee>, u64)>,
+    pub stats: SimulationStats,
+}
+
+impl Simulation {
+    pub fn new(rpc: MockRpc) -> Self {
+        Self {
+            rpc,
+            keepers: Vec::new(),
+            stats: SimulationStats::default(),
+        }
+    }
+
+    /// Adding a keeper which first ticks delay ms after the tip.
+    pub fn add_keeper(&mut self, keeper: Keeper<MockRpc, NoFee>, delay: u64) {
+        let wake_at = self.rpc.0.borrow().tip().timestamp() + delay;
+        self.keepers.push((keeper, wake_at));
+    }
+
+    /// Running keepers and mining blocks in chain time order until end. A
+    /// keeper waking up at the same time as a block ticks before it.
+    pub fn run_until(&mut self, end: u64) {
+        loop {
+            let next_block = self.rpc.0.borrow().tip().timestamp() + BLOCK_INTERVAL;
+            let next_keeper = self
+                .keepers
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, (_, wake_at))| *wake_at)
+                .map(|(index, (_, wake_at))| (index, *wake_at));
+            match next_keeper {
+                Some((index, wake_at)) if wake_at <= next_block && wake_at <= end => {
+                    let (keeper, wake_at) = &mut self.keepers[index];
+                    let result = keeper.tick();
+                    match &result {
+                        Ok(Outcome::Fresh) => {}
+                        Ok(Outcome::Updated(_)) => self.stats.updated += 1,
+                        Ok(Outcome::Lost { .. }) => self.stats.lost += 1,
+                        Err(_) => self.stats.errors += 1,
+                    }
+                    *wake_at += keeper.delay_after(&result).as_millis() as u64;
+                }
+                _ if next_block <= end => {
+                    let mut chain = self.rpc.0.borrow_mut();
+                    chain.advance(BLOCK_INTERVAL);
+                    let time_info = parse_time_info(&chain.time_cell().expect("time cell").data)
+                        .expect("time info");
+                    let staleness = chain.tip().timestamp() - u64::from(time_info.timestamp());
+                    self.stats.max_staleness = self.stats.max_staleness.max(staleness);
+                }
+                _ => break,
+            }
+        }
+    }
+}
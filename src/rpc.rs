@@ -0,0 +1,226 @@
+use std::cell::Cell;
+use std::fmt;
+
+use ckb_jsonrpc_types as json;
+use ckb_types::{
+    bytes::Bytes,
+    core::{HeaderView, TransactionView},
+    packed::{Byte32, CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use time_oracle_error::Error as ScriptError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RpcError {
+    /// Node could not be reached or returned a malformed response
+    Transport(String),
+    /// Node handled the request and returned an error
+    Rejected { code: i64, message: String },
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transport(message) => write!(f, "transport error: {}", message),
+            Self::Rejected { code, message } => {
+                write!(f, "rpc error {}: {}", code, message)?;
+                match self.type_script_error() {
+                    Some(err) => write!(f, " ({:?}: {})", err, err),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+// Error codes of the CKB node RPC.
+const TRANSACTION_FAILED_TO_RESOLVE: i64 = -301;
+const TRANSACTION_FAILED_TO_VERIFY: i64 = -302;
+const POOL_REJECTED_RBF: i64 = -1111;
+
+impl RpcError {
+    /// Whether a transaction was rejected because one of its inputs is already
+    /// spent, either by a committed transaction or by a pending one in the pool.
+    pub fn is_input_spent(&self) -> bool {
+        match self {
+            Self::Rejected { code, message } => {
+                (*code == TRANSACTION_FAILED_TO_RESOLVE && message.contains("Dead"))
+                    || *code == POOL_REJECTED_RBF
+            }
+            Self::Transport(_) => false,
+        }
+    }
+
+    /// Time type script error a transaction was rejected with. The node reports
+    /// failed scripts as `source: Inputs[0].Type, cause: ValidationFailure:
+    /// see error code 23 ...`, and the time cell is the only cell with a type
+    /// script in the transactions the keeper sends.
+    pub fn type_script_error(&self) -> Option<ScriptError> {
+        let Self::Rejected { code, message } = self else {
+            return None;
+        };
+        if *code != TRANSACTION_FAILED_TO_VERIFY {
+            return None;
+        }
+        let source = message.split("source: ").nth(1)?.split(',').next()?;
+        if !source.ends_with(".Type") {
+            return None;
+        }
+        let exit_code = message
+            .split("error code ")
+            .nth(1)?
+            .split(|c: char| c != '-' && !c.is_ascii_digit())
+            .next()?;
+        ScriptError::try_from(exit_code.parse::<i8>().ok()?).ok()
+    }
+}
+
+/// Which script of a cell the indexer matches against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptType {
+    Lock,
+    Type,
+}
+
+/// A live cell returned by the indexer.
+#[derive(Debug, Clone)]
+pub struct LiveCell {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub data: Bytes,
+    pub block_number: u64,
+}
+
+/// The subset of the CKB node and indexer RPC used by the keeper.
+pub trait CkbRpc {
+    fn get_tip_header(&self) -> Result<HeaderView, RpcError>;
+
+    fn get_header_by_number(&self, number: u64) -> Result<Option<HeaderView>, RpcError>;
+
+    /// Live cells whose lock or type script is exactly script.
+    fn get_cells(
+        &self,
+        script: &Script,
+        script_type: ScriptType,
+        limit: u32,
+    ) -> Result<Vec<LiveCell>, RpcError>;
+
+    fn send_transaction(&self, tx: &TransactionView) -> Result<Byte32, RpcError>;
+}
+
+/// CkbRpc over the JSON-RPC endpoint of a CKB node with the indexer enabled.
+pub struct HttpRpc {
+    url: String,
+    agent: ureq::Agent,
+    id: Cell<u64>,
+}
+
+#[derive(Deserialize)]
+struct IndexerPagination {
+    objects: Vec<IndexerCell>,
+}
+
+#[derive(Deserialize)]
+struct IndexerCell {
+    output: json::CellOutput,
+    output_data: Option<json::JsonBytes>,
+    out_point: json::OutPoint,
+    block_number: json::BlockNumber,
+}
+
+impl HttpRpc {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            agent: ureq::Agent::new(),
+            id: Cell::new(0),
+        }
+    }
+
+    fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, RpcError> {
+        let id = self.id.get();
+        self.id.set(id + 1);
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        let mut response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .map_err(|err| RpcError::Transport(err.to_string()))?
+            .into_json()
+            .map_err(|err| RpcError::Transport(err.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(RpcError::Rejected {
+                code: error["code"].as_i64().unwrap_or_default(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+        serde_json::from_value(response["result"].take())
+            .map_err(|err| RpcError::Transport(err.to_string()))
+    }
+}
+
+impl CkbRpc for HttpRpc {
+    fn get_tip_header(&self) -> Result<HeaderView, RpcError> {
+        let header: json::HeaderView = self.call("get_tip_header", json!([]))?;
+        Ok(header.into())
+    }
+
+    fn get_header_by_number(&self, number: u64) -> Result<Option<HeaderView>, RpcError> {
+        let header: Option<json::HeaderView> = self.call(
+            "get_header_by_number",
+            json!([json::BlockNumber::from(number)]),
+        )?;
+        Ok(header.map(Into::into))
+    }
+
+    fn get_cells(
+        &self,
+        script: &Script,
+        script_type: ScriptType,
+        limit: u32,
+    ) -> Result<Vec<LiveCell>, RpcError> {
+        let script_type = match script_type {
+            ScriptType::Lock => "lock",
+            ScriptType::Type => "type",
+        };
+        let search_key = json!({
+            "script": json::Script::from(script.clone()),
+            "script_type": script_type,
+            "script_search_mode": "exact",
+        });
+        let cells: IndexerPagination = self.call(
+            "get_cells",
+            json!([search_key, "asc", json::Uint32::from(limit)]),
+        )?;
+        Ok(cells
+            .objects
+            .into_iter()
+            .map(|cell| LiveCell {
+                out_point: cell.out_point.into(),
+                output: cell.output.into(),
+                data: cell
+                    .output_data
+                    .map(|data| data.into_bytes())
+                    .unwrap_or_default(),
+                block_number: cell.block_number.value(),
+            })
+            .collect())
+    }
+
+    fn send_transaction(&self, tx: &TransactionView) -> Result<Byte32, RpcError> {
+        let tx = json::Transaction::from(tx.data());
+        let hash: ckb_types::H256 = self.call("send_transaction", json!([tx, "passthrough"]))?;
+        Ok(hash.pack())
+    }
+}
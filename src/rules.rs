@@ -0,0 +1,90 @@
+use ckb_types::{
+    bytes::Bytes,
+    core::{HeaderView, TransactionView},
+    packed::{CellOutput, Script},
+    prelude::*,
+};
+use time_oracle_core::mock::{MockCell, MockInput, MockTx};
+
+/// An input cell of a transaction together with the header of the block which
+/// committed it.
+pub struct ResolvedInput {
+    pub output: CellOutput,
+    pub data: Bytes,
+    pub header: Option<HeaderView>,
+}
+
+/// Distinct type scripts among the input and output cells of tx which are
+/// accepted by is_time_script.
+pub fn time_scripts(
+    tx: &TransactionView,
+    inputs: &[ResolvedInput],
+    is_time_script: impl Fn(&Script) -> bool,
+) -> Vec<Script> {
+    let mut scripts: Vec<Script> = Vec::new();
+    for script in inputs
+        .iter()
+        .map(|input| input.output.clone())
+        .chain(tx.outputs())
+        .filter_map(|output| output.type_().to_opt())
+    {
+        if is_time_script(&script) && !scripts.contains(&script) {
+            scripts.push(script);
+        }
+    }
+    scripts
+}
+
+/// Running the rules of the time type script `script` from time-oracle-core
+/// against tx, without a VM.
+pub fn verify_time_type(
+    tx: &TransactionView,
+    inputs: &[ResolvedInput],
+    header_deps: &[HeaderView],
+    script: &Script,
+) -> Result<(), time_oracle_core::Error> {
+    let mock_tx = MockTx {
+        script: from_slice(script.as_slice()),
+        inputs: tx
+            .inputs()
+            .into_iter()
+            .zip(inputs)
+            .map(|(input, cell)| MockInput {
+                input: from_slice(input.as_slice()),
+                cell: mock_cell(&cell.output, &cell.data),
+                header: cell
+                    .header
+                    .as_ref()
+                    .map(|header| from_slice(header.data().as_slice())),
+            })
+            .collect(),
+        outputs: tx
+            .outputs_with_data_iter()
+            .map(|(output, data)| mock_cell(&output, &data))
+            .collect(),
+        cell_deps: Vec::new(),
+        header_deps: header_deps
+            .iter()
+            .map(|header| from_slice(header.data().as_slice()))
+            .collect(),
+    };
+    time_oracle_core::verify(&mock_tx)
+}
+
+fn mock_cell(output: &CellOutput, data: &Bytes) -> MockCell {
+    MockCell {
+        capacity: output.capacity().unpack(),
+        lock_hash: output.calc_lock_hash().unpack(),
+        type_hash: output
+            .type_()
+            .to_opt()
+            .map(|script| script.calc_script_hash().unpack()),
+        data: data.to_vec(),
+    }
+}
+
+// Converting between the ckb-types of this crate and those of ckb-std, both
+// share the same molecule encoding.
+fn from_slice<T: ckb_std::ckb_types::prelude::Entity>(slice: &[u8]) -> T {
+    T::from_slice(slice).expect("same molecule encoding")
+}
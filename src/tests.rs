@@ -0,0 +1,527 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use ckb_crypto::secp::{Privkey, Signature};
+use ckb_hash::{blake2b_256, new_blake2b};
+use ckb_mock_tx_types::{MockInfo, MockInput, MockTransaction, ReprMockTransaction};
+use ckb_types::{
+    H256,
+    bytes::Bytes,
+    core::{EpochNumberWithFraction, ScriptHashType, TransactionView},
+    packed::{Byte32, CellDep, CellInput, CellOutput, OutPoint, Script, WitnessArgs},
+    prelude::*,
+};
+use time_oracle_core::calc_type_id;
+use time_oracle_error::Error as ScriptError;
+use time_oracle_sdk::{
+    Byte32Vec, TIME_INFO_VERSION, TimeArgs, TimeInfo, TimeOracle, parse_time_info,
+};
+
+use crate::config::Config;
+use crate::error::Error;
+use crate::inspect;
+use crate::keeper::{Backoff, Keeper, KeeperConfig, Outcome};
+use crate::mock::{BLOCK_INTERVAL, MockChain, MockRpc, NoFee, Simulation};
+use crate::rpc::{CkbRpc, LiveCell, RpcError, ScriptType};
+use crate::wallet::{SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH, Secp256k1Wallet};
+
+const TIME_CODE_HASH: [u8; 32] = [9; 32];
+const GENESIS_TIMESTAMP: u64 = 1_700_000_000_000;
+const UPDATE_INTERVAL: u64 = 10 * 60 * 1000;
+
+// Mock chain holding a time cell created in the genesis block, whose type args
+// carry args_extension.
+fn setup(args_extension: &[u8]) -> (MockRpc, KeeperConfig) {
+    let mut chain = MockChain::new(Byte32::new(TIME_CODE_HASH), GENESIS_TIMESTAMP);
+    let lock = Script::new_builder()
+        .code_hash(Byte32::new([7; 32]))
+        .build();
+    let oracle = TimeOracle::new(Byte32::new(TIME_CODE_HASH), ScriptHashType::Type, vec![]);
+    let type_script = oracle
+        .type_script(&CellInput::default(), 0, &lock)
+        .expect("type script");
+    let args: Bytes = type_script.args().unpack();
+    let type_script = type_script
+        .as_builder()
+        .args(Bytes::from([args.as_ref(), args_extension].concat()).pack())
+        .build();
+    let data = TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(GENESIS_TIMESTAMP.into())
+        .build()
+        .as_slice()
+        .to_vec();
+    chain.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock)
+            .type_(Some(type_script.clone()).pack())
+            .build(),
+        Bytes::from(data),
+    );
+
+    let config = KeeperConfig {
+        time_type: type_script,
+        cell_deps: vec![],
+        update_interval: UPDATE_INTERVAL,
+        poll_interval: Duration::from_secs(60),
+        retry_delay: Duration::from_secs(10),
+        max_retry_delay: Duration::from_secs(80),
+        jitter_seed: 0,
+    };
+    (MockRpc::new(chain), config)
+}
+
+fn oracle_timestamp(rpc: &MockRpc) -> u64 {
+    let chain = rpc.0.borrow();
+    let cell = chain.time_cell().expect("time cell");
+    u64::from(parse_time_info(&cell.data).expect("time info").timestamp())
+}
+
+fn mine(rpc: &MockRpc) {
+    rpc.0.borrow_mut().advance(BLOCK_INTERVAL);
+}
+
+#[test]
+fn keeper_updates_stale_oracle() {
+    let (rpc, config) = setup(&[]);
+    rpc.0.borrow_mut().advance(UPDATE_INTERVAL);
+    let mut keeper = Keeper::new(config, rpc.clone(), NoFee).unwrap();
+
+    assert!(matches!(keeper.tick(), Ok(Outcome::Updated(_))));
+    mine(&rpc);
+    assert_eq!(oracle_timestamp(&rpc), GENESIS_TIMESTAMP + UPDATE_INTERVAL);
+    assert_eq!(rpc.0.borrow().committed.len(), 1);
+}
+
+#[test]
+fn keeper_skips_fresh_oracle() {
+    let (rpc, config) = setup(&[]);
+    rpc.0.borrow_mut().advance(UPDATE_INTERVAL - 1);
+    let mut keeper = Keeper::new(config, rpc.clone(), NoFee).unwrap();
+
+    assert_eq!(keeper.tick().unwrap(), Outcome::Fresh);
+    assert!(rpc.0.borrow().committed.is_empty());
+}
+
+#[test]
+fn keeper_keeps_oracle_fresh() {
+    let (rpc, config) = setup(&[]);
+    let mut keeper = Keeper::new(config, rpc.clone(), NoFee).unwrap();
+
+    // poll every minute of chain time for three hours, an update is committed
+    // by the block of the next poll
+    for _ in 0..180 {
+        rpc.0.borrow_mut().advance(60_000);
+        let tip_timestamp = rpc.0.borrow().tip().timestamp();
+        assert!(tip_timestamp - oracle_timestamp(&rpc) <= UPDATE_INTERVAL + 60_000);
+        keeper.tick().unwrap();
+    }
+    mine(&rpc);
+    // one update every ten polls
+    assert_eq!(rpc.0.borrow().committed.len(), 18);
+}
+
+#[test]
+fn keeper_with_min_update_interval() {
+    let extension = [
+        [1].as_slice(),
+        &0u64.to_le_bytes(),
+        &UPDATE_INTERVAL.to_le_bytes(),
+    ]
+    .concat();
+    let (rpc, config) = setup(&extension);
+    let mut keeper = Keeper::new(config, rpc.clone(), NoFee).unwrap();
+
+    // since of the update lags the tip by the since tolerance of the contract
+    rpc.0.borrow_mut().advance(2 * UPDATE_INTERVAL - 1000);
+    let err = match keeper.tick() {
+        Err(Error::Rpc(err)) => err,
+        result => panic!("unexpected result {:?}", result),
+    };
+    assert_eq!(
+        err.type_script_error(),
+        Some(ScriptError::UpdateTooFrequent)
+    );
+    assert!(
+        err.to_string().ends_with(
+            "(UpdateTooFrequent: time cell is updated sooner than the minimum interval)"
+        )
+    );
+    assert!(rpc.0.borrow().committed.is_empty());
+
+    rpc.0.borrow_mut().advance(1000);
+    assert!(matches!(keeper.tick(), Ok(Outcome::Updated(_))));
+}
+
+#[test]
+fn type_script_error_from_rejection() {
+    let rejected = |code, message: &str| RpcError::Rejected {
+        code,
+        message: message.to_string(),
+    };
+    let err = rejected(
+        -302,
+        "TransactionFailedToVerify: Verification failed Script(TransactionScriptError { source: Outputs[0].Type, cause: ValidationFailure: see error code 23 on page https://nervosnetwork.github.io/ckb-script-error-codes/by-type-hash/0x.html#23 })",
+    );
+    assert_eq!(
+        err.type_script_error(),
+        Some(ScriptError::InvalidTypeIDLock)
+    );
+    assert!(err.to_string().ends_with(
+        "(InvalidTypeIDLock: output lock is not the lock configured in the time type args)"
+    ));
+
+    // lock script failures carry codes of another script
+    let err = rejected(
+        -302,
+        "TransactionFailedToVerify: Verification failed Script(TransactionScriptError { source: Inputs[1].Lock, cause: ValidationFailure: see error code -31 on page https://nervosnetwork.github.io/ckb-script-error-codes/by-type-hash/0x.html#-31 })",
+    );
+    assert_eq!(err.type_script_error(), None);
+    assert_eq!(rejected(-301, "error code 23").type_script_error(), None);
+}
+
+#[test]
+fn keeper_without_time_cell() {
+    let (rpc, mut config) = setup(&[]);
+    config.time_type = config
+        .time_type
+        .as_builder()
+        .args(Bytes::new().pack())
+        .build();
+    let mut keeper = Keeper::new(config, rpc, NoFee).unwrap();
+
+    assert!(matches!(keeper.tick(), Err(Error::TimeCellMissing)));
+}
+
+// MockRpc where a competing keeper gets its update committed right before ours
+// is sent.
+struct RacingRpc {
+    inner: MockRpc,
+    competitor: Rc<RefCell<Option<TransactionView>>>,
+}
+
+impl CkbRpc for RacingRpc {
+    fn get_tip_header(&self) -> Result<ckb_types::core::HeaderView, RpcError> {
+        self.inner.get_tip_header()
+    }
+
+    fn get_header_by_number(
+        &self,
+        number: u64,
+    ) -> Result<Option<ckb_types::core::HeaderView>, RpcError> {
+        self.inner.get_header_by_number(number)
+    }
+
+    fn get_cells(
+        &self,
+        script: &Script,
+        script_type: ScriptType,
+        limit: u32,
+    ) -> Result<Vec<LiveCell>, RpcError> {
+        self.inner.get_cells(script, script_type, limit)
+    }
+
+    fn send_transaction(&self, tx: &TransactionView) -> Result<Byte32, RpcError> {
+        if let Some(competitor) = self.competitor.borrow_mut().take() {
+            self.inner.send_transaction(&competitor)?;
+            self.inner.0.borrow_mut().advance(BLOCK_INTERVAL);
+        }
+        self.inner.send_transaction(tx)
+    }
+}
+
+#[test]
+fn keeper_loses_race() {
+    let (rpc, config) = setup(&[]);
+    rpc.0.borrow_mut().advance(UPDATE_INTERVAL);
+    let competitor = Rc::new(RefCell::new(None));
+    let mut keeper = Keeper::new(
+        config,
+        RacingRpc {
+            inner: rpc.clone(),
+            competitor: competitor.clone(),
+        },
+        NoFee,
+    )
+    .unwrap();
+    let oracle = TimeOracle::new(Byte32::new(TIME_CODE_HASH), ScriptHashType::Type, vec![]);
+    let competitor_tx = oracle
+        .build_update_tx(
+            &keeper.load_live_time_cell().unwrap(),
+            &rpc.0.borrow().tip(),
+        )
+        .unwrap();
+    competitor.replace(Some(competitor_tx.clone()));
+
+    // our update is rejected as its input is spent, and the oracle refreshed
+    // by the competitor needs no further update
+    assert_eq!(keeper.tick().unwrap(), Outcome::Fresh);
+    assert_eq!(rpc.0.borrow().committed, vec![competitor_tx]);
+}
+
+#[test]
+fn keeper_loses_race_in_pool() {
+    let (rpc, config) = setup(&[]);
+    rpc.0.borrow_mut().advance(UPDATE_INTERVAL);
+    let retry_delay = config.retry_delay;
+    let mut first = Keeper::new(config.clone(), rpc.clone(), NoFee).unwrap();
+    let mut second = Keeper::new(config, rpc.clone(), NoFee).unwrap();
+
+    // the pending update of the first keeper leaves the oracle stale until the
+    // next block, so the second keeper backs off and retries
+    assert!(matches!(first.tick(), Ok(Outcome::Updated(_))));
+    let Ok(Outcome::Lost { retry_after }) = second.tick() else {
+        panic!("second keeper should lose the race");
+    };
+    assert!(retry_after >= retry_delay / 2 && retry_after <= retry_delay);
+
+    mine(&rpc);
+    assert_eq!(second.tick().unwrap(), Outcome::Fresh);
+    assert_eq!(rpc.0.borrow().committed.len(), 1);
+}
+
+#[test]
+fn backoff_doubles_up_to_max() {
+    let base = Duration::from_secs(10);
+    let max = Duration::from_secs(80);
+    let mut backoff = Backoff::new(base, max, 42);
+    for attempt in 0..6 {
+        let ceiling = (base * (1 << attempt)).min(max);
+        let delay = backoff.next_delay();
+        assert!(delay >= ceiling / 2 && delay <= ceiling, "{:?}", delay);
+    }
+
+    backoff.reset();
+    assert!(backoff.next_delay() <= base);
+}
+
+#[test]
+fn backoff_jitter_depends_on_seed() {
+    let delays = |seed| {
+        let mut backoff = Backoff::new(Duration::from_secs(10), Duration::from_secs(80), seed);
+        (0..4).map(|_| backoff.next_delay()).collect::<Vec<_>>()
+    };
+    assert_eq!(delays(1), delays(1));
+    assert_ne!(delays(1), delays(2));
+}
+
+#[test]
+fn competing_keepers_keep_oracle_fresh() {
+    let (rpc, config) = setup(&[]);
+    let mut simulation = Simulation::new(rpc.clone());
+    // keepers polling at the same time, so that every update is contended
+    for seed in 0..3 {
+        let config = KeeperConfig {
+            jitter_seed: seed,
+            ..config.clone()
+        };
+        simulation.add_keeper(Keeper::new(config, rpc.clone(), NoFee).unwrap(), 0);
+    }
+
+    simulation.run_until(GENESIS_TIMESTAMP + 3 * 60 * 60 * 1000);
+    let stats = &simulation.stats;
+    assert_eq!(stats.errors, 0);
+    assert_eq!(stats.updated, rpc.0.borrow().committed.len());
+    assert!(stats.updated >= 15);
+    // the first update is lost by both other keepers, whose jittered retries
+    // then move their polls apart
+    assert!(stats.lost >= 2);
+    assert!(stats.lost <= 2 * stats.updated);
+    assert!(stats.max_staleness <= UPDATE_INTERVAL + 60_000 + BLOCK_INTERVAL);
+}
+
+// Mock transaction dump of tx spending empty cells.
+fn mock_tx_json(tx: &TransactionView) -> String {
+    let inputs = tx
+        .inputs()
+        .into_iter()
+        .map(|input| MockInput {
+            input,
+            output: CellOutput::new_builder().capacity(1000u64.pack()).build(),
+            data: Bytes::new(),
+            header: None,
+        })
+        .collect();
+    let mock_tx = MockTransaction {
+        mock_info: MockInfo {
+            inputs,
+            ..Default::default()
+        },
+        tx: tx.data(),
+    };
+    serde_json::to_string(&ReprMockTransaction::from(mock_tx)).unwrap()
+}
+
+#[test]
+fn inspect_readme_args() {
+    let report = inspect::run(&[
+        "args".to_string(),
+        "0x7ac202f08b41448259ca387eee84ac4c285a33124628841ad7ee1815c61ec49108d1374b76cb5104ace0e2394b5d873f05c7ed8e8659d54b4cc29a98bf66b820".to_string(),
+    ])
+    .unwrap();
+    assert_eq!(
+        report,
+        "type_id: 0x7ac202f08b41448259ca387eee84ac4c285a33124628841ad7ee1815c61ec491\n\
+         lock_hash: 0x08d1374b76cb5104ace0e2394b5d873f05c7ed8e8659d54b4cc29a98bf66b820\n\
+         header_dep_window: 600000 ms\n\
+         min_update_interval: 0 ms\n\
+         min_fee: 0 shannons\n"
+    );
+}
+
+#[test]
+fn inspect_args_extension() {
+    let args = [
+        [0; 64].as_slice(),
+        &[1],
+        &60_000u64.to_le_bytes(),
+        &UPDATE_INTERVAL.to_le_bytes(),
+    ]
+    .concat();
+    let report = inspect::inspect_args(&args);
+    assert!(report.contains("header_dep_window: 60000 ms\n"));
+    assert!(report.contains("min_update_interval: 600000 ms\n"));
+
+    let time_args = TimeArgs::new_builder()
+        .min_fee(100u64.into())
+        .keepers(Byte32Vec::new_builder().push([0xab; 32].into()).build())
+        .build();
+    let args = [[0; 64].as_slice(), &[3], time_args.as_slice()].concat();
+    assert!(inspect::inspect_args(&args).ends_with(&format!(
+        "min_update_interval: 0 ms\nmin_fee: 100 shannons\nkeeper: 0x{}\n",
+        "ab".repeat(32)
+    )));
+
+    let time_args = TimeArgs::new_builder()
+        .shard_index(1.into())
+        .shard_count(4.into())
+        .build();
+    let args = [[0; 64].as_slice(), &[3], time_args.as_slice()].concat();
+    assert!(inspect::inspect_args(&args).ends_with("shard: 1 of 4\n"));
+
+    let args = [[0; 64].as_slice(), &[9]].concat();
+    assert!(inspect::inspect_args(&args).contains(
+        "error: InvalidArgsVersion (31): version of the args extension is not supported"
+    ));
+    assert!(inspect::inspect_args(&[0; 32]).starts_with("error: ArgsLengthNotEnough (22)"));
+}
+
+#[test]
+fn inspect_time_info_data() {
+    let data = TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(GENESIS_TIMESTAMP.into())
+        .block_number(5u64.into())
+        .epoch(EpochNumberWithFraction::new(1, 5, 1800).full_value().into())
+        .sequence(3u64.into())
+        .build();
+    assert_eq!(
+        inspect::inspect_data(data.as_slice()),
+        "version: 1\ntimestamp: 1700000000000\nblock_number: 5\nepoch: 1 (5/1800)\nsequence: 3\n"
+    );
+    assert!(inspect::inspect_data(&[1]).starts_with("error: InvalidTimeInfo (24)"));
+}
+
+#[test]
+fn inspect_mock_tx() {
+    let oracle = TimeOracle::new(Byte32::new(TIME_CODE_HASH), ScriptHashType::Type, vec![]);
+    let lock = Script::new_builder()
+        .code_hash(Byte32::new([7; 32]))
+        .build();
+    let input = CellInput::new(OutPoint::new(Byte32::new([1; 32]), 0), 0);
+    let tx = oracle.build_create_tx(&input, 0, lock).expect("create tx");
+    let report = inspect::inspect_tx(&mock_tx_json(&tx), None).unwrap();
+    assert!(report.contains("  output 0:\n    version: 1\n"));
+    assert!(report.ends_with("  result: ok\n"));
+
+    // type id committing to another output index
+    let type_script = tx.output(0).unwrap().type_().to_opt().unwrap();
+    let mut args: Vec<u8> = type_script.args().raw_data().to_vec();
+    args[..32].copy_from_slice(&calc_type_id(input.as_slice(), 1));
+    let output = tx
+        .output(0)
+        .unwrap()
+        .as_builder()
+        .type_(
+            Some(
+                type_script
+                    .as_builder()
+                    .args(Bytes::from(args).pack())
+                    .build(),
+            )
+            .pack(),
+        )
+        .build();
+    let tx = tx.as_advanced_builder().set_outputs(vec![output]).build();
+    let report =
+        inspect::inspect_tx(&mock_tx_json(&tx), Some(Byte32::new(TIME_CODE_HASH))).unwrap();
+    assert!(report.ends_with(
+        "  result: TypeIDNotMatch (21): type id in args does not match the first input\n"
+    ));
+}
+
+#[test]
+fn parse_example_config() {
+    let config = Config::parse(include_str!("../keeper.example.toml")).unwrap();
+    let keeper = config.keeper();
+    assert_eq!(keeper.update_interval, UPDATE_INTERVAL);
+    assert_eq!(keeper.time_type.args().raw_data().len(), 64);
+    assert_eq!(keeper.cell_deps.len(), 2);
+}
+
+#[test]
+fn wallet_pays_fee_and_signs() {
+    let (rpc, config) = setup(&[]);
+    rpc.0.borrow_mut().advance(UPDATE_INTERVAL);
+    let privkey = Privkey::from(H256([1; 32]));
+    let wallet = Secp256k1Wallet::new(privkey.clone(), CellDep::default(), 100_000).unwrap();
+    let lock = Script::new_builder()
+        .code_hash(SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(
+            Bytes::copy_from_slice(&blake2b_256(privkey.pubkey().unwrap().serialize())[..20])
+                .pack(),
+        )
+        .build();
+    rpc.0.borrow_mut().create_cell(
+        CellOutput::new_builder()
+            .capacity(100_0000_0000u64.pack())
+            .lock(lock.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let mut keeper = Keeper::new(config, rpc.clone(), wallet).unwrap();
+
+    assert!(matches!(keeper.tick(), Ok(Outcome::Updated(_))));
+    mine(&rpc);
+    let tx = rpc.0.borrow().committed[0].clone();
+    assert_eq!(tx.inputs().len(), 2);
+    let change = tx.output(1).unwrap();
+    assert_eq!(change.lock(), lock);
+    assert_eq!(
+        Unpack::<u64>::unpack(&change.capacity()),
+        100_0000_0000 - 100_000
+    );
+
+    // signature over the tx hash and the witness with an empty signature
+    let witness = WitnessArgs::from_slice(&tx.witnesses().get(1).unwrap().raw_data()).unwrap();
+    let signature: Bytes = witness.lock().to_opt().unwrap().unpack();
+    let placeholder = witness
+        .as_builder()
+        .lock(Some(Bytes::from(vec![0u8; 65])).pack())
+        .build()
+        .as_bytes();
+    let mut blake2b = new_blake2b();
+    blake2b.update(tx.hash().as_slice());
+    blake2b.update(&(placeholder.len() as u64).to_le_bytes());
+    blake2b.update(&placeholder);
+    let mut message = [0u8; 32];
+    blake2b.finalize(&mut message);
+    let pubkey = Signature::from_slice(&signature)
+        .unwrap()
+        .recover(&H256(message))
+        .unwrap();
+    assert_eq!(pubkey, privkey.pubkey().unwrap());
+}
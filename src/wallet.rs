@@ -0,0 +1,122 @@
+use ckb_crypto::secp::Privkey;
+use ckb_hash::{blake2b_256, new_blake2b};
+use ckb_types::{
+    H256,
+    bytes::Bytes,
+    core::{ScriptHashType, TransactionView},
+    packed::{CellDep, CellInput, CellOutput, Script, WitnessArgs},
+    prelude::*,
+};
+
+use crate::error::Error;
+use crate::rpc::{CkbRpc, ScriptType};
+
+// Type hash of the secp256k1_blake160_sighash_all lock in the genesis block.
+pub const SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH: [u8; 32] = [
+    0x9b, 0xd7, 0xe0, 0x6f, 0x3e, 0xcf, 0x4b, 0xe0, 0xf2, 0xfc, 0xd2, 0x18, 0x8b, 0x23, 0xf1, 0xb9,
+    0xfc, 0xc8, 0x8e, 0x5d, 0x4b, 0x65, 0xa8, 0x63, 0x7b, 0x17, 0x72, 0x3b, 0xbd, 0xa3, 0xcc, 0xe8,
+];
+// Occupied capacity in shannons of a change cell locked by secp256k1_blake160.
+const MIN_CHANGE_CAPACITY: u64 = 61_0000_0000;
+const SIGNATURE_SIZE: usize = 65;
+
+/// Pays the fee of update transactions, so that the time cell keeps its
+/// capacity.
+pub trait Wallet {
+    /// Adding inputs paying the fee, change outputs and witnesses to tx.
+    fn complete_tx<R: CkbRpc>(
+        &self,
+        rpc: &R,
+        tx: TransactionView,
+    ) -> Result<TransactionView, Error>;
+}
+
+/// Wallet paying a fixed fee from a cell locked by secp256k1_blake160_sighash_all.
+pub struct Secp256k1Wallet {
+    privkey: Privkey,
+    lock: Script,
+    cell_dep: CellDep,
+    fee: u64,
+}
+
+impl Secp256k1Wallet {
+    /// cell_dep must reference the secp256k1_blake160 dep group.
+    pub fn new(privkey: Privkey, cell_dep: CellDep, fee: u64) -> Result<Self, Error> {
+        let pubkey = privkey
+            .pubkey()
+            .map_err(|err| Error::Sign(err.to_string()))?;
+        let lock = Script::new_builder()
+            .code_hash(SECP256K1_BLAKE160_SIGHASH_ALL_TYPE_HASH.pack())
+            .hash_type(ScriptHashType::Type.into())
+            .args(Bytes::copy_from_slice(&blake2b_256(pubkey.serialize())[..20]).pack())
+            .build();
+        Ok(Self {
+            privkey,
+            lock,
+            cell_dep,
+            fee,
+        })
+    }
+
+    // Signing the last input, the only one locked by the wallet.
+    fn sign(&self, tx: TransactionView) -> Result<TransactionView, Error> {
+        let index = tx.inputs().len() - 1;
+        let placeholder = WitnessArgs::new_builder()
+            .lock(Some(Bytes::from(vec![0u8; SIGNATURE_SIZE])).pack())
+            .build();
+        let placeholder = placeholder.as_bytes();
+
+        let mut blake2b = new_blake2b();
+        blake2b.update(tx.hash().as_slice());
+        blake2b.update(&(placeholder.len() as u64).to_le_bytes());
+        blake2b.update(&placeholder);
+        let mut message = [0u8; 32];
+        blake2b.finalize(&mut message);
+
+        let signature = self
+            .privkey
+            .sign_recoverable(&H256::from(message))
+            .map_err(|err| Error::Sign(err.to_string()))?;
+        let witness = WitnessArgs::new_builder()
+            .lock(Some(Bytes::from(signature.serialize())).pack())
+            .build();
+
+        let mut witnesses: Vec<_> = tx.witnesses().into_iter().collect();
+        witnesses.resize(index, Default::default());
+        witnesses.push(witness.as_bytes().pack());
+        Ok(tx.as_advanced_builder().set_witnesses(witnesses).build())
+    }
+}
+
+impl Wallet for Secp256k1Wallet {
+    fn complete_tx<R: CkbRpc>(
+        &self,
+        rpc: &R,
+        tx: TransactionView,
+    ) -> Result<TransactionView, Error> {
+        let cell = rpc
+            .get_cells(&self.lock, ScriptType::Lock, 100)?
+            .into_iter()
+            .find(|cell| {
+                let capacity: u64 = cell.output.capacity().unpack();
+                cell.output.type_().is_none()
+                    && cell.data.is_empty()
+                    && capacity >= self.fee + MIN_CHANGE_CAPACITY
+            })
+            .ok_or(Error::FeeCellMissing)?;
+        let capacity: u64 = cell.output.capacity().unpack();
+        let change = CellOutput::new_builder()
+            .capacity((capacity - self.fee).pack())
+            .lock(self.lock.clone())
+            .build();
+
+        let tx = tx
+            .as_advanced_builder()
+            .input(CellInput::new(cell.out_point, 0))
+            .output(change)
+            .output_data(Bytes::new().pack())
+            .cell_dep(self.cell_dep.clone())
+            .build();
+        self.sign(tx)
+    }
+}
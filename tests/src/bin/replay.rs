@@ -0,0 +1,47 @@
+//! Replaying a transaction dumped into failed_txs against the time binary in
+//! build/$MODE, printing the exit code and cycles of every script group.
+//!
+//! ```text
+//! make build && cargo run --bin replay -- failed_txs/0x....json
+//! ```
+
+use ckb_mock_tx_types::ReprMockTransaction;
+use std::env;
+use std::fs;
+use std::process;
+use tests::replay::replay;
+use tests::Loader;
+
+const MAX_CYCLES: u64 = 70_000_000;
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: replay <failed_txs/0x....json>");
+            process::exit(2);
+        }
+    };
+    let json = fs::read_to_string(&path).unwrap_or_else(|err| {
+        eprintln!("{}: {}", path, err);
+        process::exit(2);
+    });
+    let mock_tx: ReprMockTransaction = serde_json::from_str(&json).unwrap_or_else(|err| {
+        eprintln!("{} is not a mock tx: {}", path, err);
+        process::exit(2);
+    });
+
+    let reports = replay(mock_tx, Loader::default().load_binary("time"), MAX_CYCLES)
+        .unwrap_or_else(|err| {
+            eprintln!("failed to replay {}: {}", path, err);
+            process::exit(2);
+        });
+    let mut failed = false;
+    for report in reports {
+        println!("{}", report);
+        failed |= report.result.is_err();
+    }
+    if failed {
+        process::exit(1);
+    }
+}
@@ -1,16 +1,25 @@
 use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
     ckb_error::Error,
     ckb_types::{
         bytes::Bytes,
-        core::{Cycle, TransactionView},
+        core::{
+            Cycle, EpochNumberWithFraction, HeaderBuilder, HeaderView, ScriptHashType,
+            TransactionView,
+        },
+        packed::{CellDep, CellInput, CellOutput, OutPoint, Script},
+        prelude::*,
     },
     context::Context,
 };
 use std::env;
+use std::fmt;
 use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
+use time_oracle_sdk::{LiveTimeCell, TimeInfo, TimeOracle, TIME_INFO_VERSION};
 
+pub mod replay;
 #[cfg(test)]
 mod tests;
 
@@ -90,6 +99,101 @@ impl Loader {
     }
 }
 
+// Blocks per epoch of the headers built by the tests.
+const EPOCH_LENGTH: u64 = 1800;
+
+/// Epoch of the block at number, as stored in headers and TimeInfo.
+pub fn epoch(number: u64) -> u64 {
+    EpochNumberWithFraction::new(number / EPOCH_LENGTH, number % EPOCH_LENGTH, EPOCH_LENGTH)
+        .full_value()
+}
+
+/// Header of the block at number and timestamp. ckb-types asserts in debug
+/// builds that headers after genesis carry a well formed epoch.
+pub fn header(number: u64, timestamp: u64) -> HeaderView {
+    HeaderBuilder::default()
+        .number(number.pack())
+        .timestamp(timestamp.pack())
+        .epoch(epoch(number).pack())
+        .build()
+}
+
+/// Deployed time type script, always success lock and the contract reading
+/// the oracle under test.
+pub struct OracleSetup {
+    pub context: Context,
+    pub oracle: TimeOracle,
+    pub contract_out_point: OutPoint,
+    pub always_success: Script,
+    /// Type of the time cells created by live_time_cell.
+    pub time_type: Script,
+}
+
+impl OracleSetup {
+    pub fn new(contract: &str) -> Self {
+        let mut context = Context::default();
+        let time_out_point = context.deploy_cell(Loader::default().load_binary("time"));
+        let contract_out_point = context.deploy_cell(Loader::default().load_binary(contract));
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let always_success = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let time_script = context
+            .build_script(&time_out_point, Bytes::new())
+            .expect("script");
+
+        let oracle = TimeOracle::new(
+            time_script.code_hash(),
+            ScriptHashType::Type,
+            vec![
+                CellDep::new_builder().out_point(time_out_point).build(),
+                CellDep::new_builder()
+                    .out_point(always_success_out_point)
+                    .build(),
+            ],
+        );
+        let time_type = oracle
+            .type_script(&CellInput::default(), 0, &always_success)
+            .expect("type script");
+        Self {
+            context,
+            oracle,
+            contract_out_point,
+            always_success,
+            time_type,
+        }
+    }
+
+    /// A live time cell at block 100 whose oracle time is timestamp.
+    pub fn live_time_cell(&mut self, timestamp: u64) -> LiveTimeCell {
+        let output = CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(self.always_success.clone())
+            .type_(Some(self.time_type.clone()).pack())
+            .build();
+        let data = TimeInfo::new_builder()
+            .version(TIME_INFO_VERSION.into())
+            .timestamp(timestamp.into())
+            .block_number(100.into())
+            .epoch(epoch(100).into())
+            .build()
+            .as_bytes();
+        let out_point = self.context.create_cell(output.clone(), data.clone());
+        LiveTimeCell {
+            out_point,
+            output,
+            data,
+            block_hash: None,
+        }
+    }
+
+    /// Cell dep of a new live time cell whose oracle time is timestamp.
+    pub fn time_cell_dep(&mut self, timestamp: u64) -> CellDep {
+        let out_point = self.live_time_cell(timestamp).out_point;
+        CellDep::new_builder().out_point(out_point).build()
+    }
+}
+
 // This helper method runs Context::verify_tx, but in case error happens,
 // it also dumps current transaction to failed_txs folder.
 pub fn verify_and_dump_failed_tx(
@@ -111,12 +215,17 @@ pub fn verify_and_dump_failed_tx(
     result
 }
 
-pub fn assert_script_error(err: ckb_testtool::ckb_error::Error, err_code: i8) {
+pub fn assert_script_error<E>(err: ckb_testtool::ckb_error::Error, expected: E)
+where
+    E: Into<i8> + fmt::Debug + Copy,
+{
     let error_string = err.to_string();
+    let err_code: i8 = expected.into();
     assert!(
         error_string.contains(format!("error code {} ", err_code).as_str()),
-        "error_string: {}, expected_error_code: {}",
+        "error_string: {}, expected_error: {:?} ({})",
         error_string,
+        expected,
         err_code
     );
 }
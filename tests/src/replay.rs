@@ -0,0 +1,137 @@
+//! Replaying mock transactions dumped by `verify_and_dump_failed_tx` against a
+//! freshly built time binary, one script group at a time.
+
+use ckb_mock_tx_types::{MockTransaction, ReprMockTransaction, Resource};
+use ckb_testtool::ckb_chain_spec::consensus::ConsensusBuilder;
+use ckb_testtool::ckb_script::{
+    ScriptError, ScriptGroupType, TransactionScriptsVerifier, TxVerifyEnv,
+};
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{
+        cell::resolve_transaction,
+        hardfork::{HardForks, CKB2021, CKB2023},
+        Cycle, HeaderBuilder, ScriptHashType,
+    },
+    packed::{Byte32, Script},
+    prelude::*,
+};
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use time_oracle_error::Error;
+
+// Bytes of type_id and lock hash at the beginning of the time type args.
+const TIME_ARGS_LENGTH: usize = 64;
+
+/// Result of running one script group of the replayed transaction.
+pub struct GroupReport {
+    pub group_type: ScriptGroupType,
+    pub script_hash: Byte32,
+    /// Whether the group runs the replaced time binary
+    pub is_time: bool,
+    pub result: Result<Cycle, ScriptError>,
+}
+
+impl GroupReport {
+    /// Exit code of the script, if it ran to a non-zero exit.
+    pub fn exit_code(&self) -> Option<i8> {
+        match &self.result {
+            Err(ScriptError::ValidationFailure(_, code)) => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GroupReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} script {:#x}", self.group_type, self.script_hash)?;
+        if self.is_time {
+            write!(f, " (time)")?;
+        }
+        match (&self.result, self.exit_code()) {
+            (Ok(cycles), _) => write!(f, ": exit code 0, {} cycles", cycles),
+            (Err(_), Some(code)) if self.is_time => match Error::try_from(code) {
+                Ok(err) => write!(f, ": exit code {} {:?}, {}", code, err, err),
+                Err(_) => write!(f, ": exit code {}", code),
+            },
+            (Err(_), Some(code)) => write!(f, ": exit code {}", code),
+            (Err(err), None) => write!(f, ": {}", err),
+        }
+    }
+}
+
+/// Running every script group of mock_tx, with the cell dep of the time type
+/// script replaced by time_binary. Time type scripts are the type scripts of
+/// hash type `type` whose args hold at least type_id and lock hash, as built
+/// by the tests.
+pub fn replay(
+    mock_tx: ReprMockTransaction,
+    time_binary: Bytes,
+    max_cycles: Cycle,
+) -> Result<Vec<GroupReport>, String> {
+    let mut mock_tx = MockTransaction::from(mock_tx);
+    let tx = mock_tx.core_transaction();
+
+    let time_code_hashes: HashSet<Byte32> = mock_tx
+        .mock_info
+        .inputs
+        .iter()
+        .map(|input| input.output.clone())
+        .chain(tx.outputs())
+        .filter_map(|output| output.type_().to_opt())
+        .filter(is_time_script)
+        .map(|script| script.code_hash())
+        .collect();
+    let mut replaced = false;
+    for dep in mock_tx.mock_info.cell_deps.iter_mut() {
+        let type_hash = dep
+            .output
+            .type_()
+            .to_opt()
+            .map(|script| script.calc_script_hash());
+        if type_hash.is_some_and(|hash| time_code_hashes.contains(&hash)) {
+            dep.data = time_binary.clone();
+            replaced = true;
+        }
+    }
+    if !replaced {
+        return Err("no cell dep of the time type script in the mock tx".to_string());
+    }
+
+    let resource = Resource::from_mock_tx(&mock_tx)?;
+    let rtx = resolve_transaction(tx, &mut HashSet::new(), &resource, &resource)
+        .map_err(|err| err.to_string())?;
+    // same consensus and tip as ckb_testtool::context::Context::verify_tx
+    let consensus = ConsensusBuilder::default()
+        .hardfork_switch(HardForks {
+            ckb2021: CKB2021::new_dev_default(),
+            ckb2023: CKB2023::new_dev_default(),
+        })
+        .build();
+    let tip = HeaderBuilder::default().number(0.pack()).build();
+    let mut verifier = TransactionScriptsVerifier::new(
+        Arc::new(rtx),
+        resource,
+        Arc::new(consensus),
+        Arc::new(TxVerifyEnv::new_submit(&tip)),
+    );
+    verifier.set_debug_printer(|_id, message| {
+        println!("[contract debug] {}", message);
+    });
+
+    Ok(verifier
+        .groups()
+        .map(|(hash, group)| GroupReport {
+            group_type: group.group_type,
+            script_hash: hash.clone(),
+            is_time: group.group_type == ScriptGroupType::Type && is_time_script(&group.script),
+            result: verifier.verify_single(group.group_type, hash, max_cycles),
+        })
+        .collect())
+}
+
+fn is_time_script(script: &Script) -> bool {
+    script.hash_type() == ScriptHashType::Type.into()
+        && script.args().raw_data().len() >= TIME_ARGS_LENGTH
+}
@@ -0,0 +1,141 @@
+use crate::assert_script_error;
+use crate::header;
+use crate::OracleSetup;
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{ScriptHashType, TransactionView},
+    packed::*,
+    prelude::*,
+};
+use time_oracle_error::CheckpointError;
+use time_oracle_sdk::{Checkpoint, TimeInfo};
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+// Deployed time and checkpoint type scripts, with an oracle creating a
+// checkpoint on every update.
+fn setup() -> OracleSetup {
+    let mut setup = OracleSetup::new("checkpoint");
+    let checkpoint_script = setup
+        .context
+        .build_script(&setup.contract_out_point, Bytes::new())
+        .expect("script");
+    setup.oracle = setup.oracle.clone().with_checkpoint(Checkpoint {
+        code_hash: checkpoint_script.code_hash(),
+        hash_type: ScriptHashType::Type,
+        cell_dep: CellDep::new_builder()
+            .out_point(setup.contract_out_point.clone())
+            .build(),
+        lock: setup.always_success.clone(),
+    });
+    setup
+}
+
+// An update of a fresh live time cell, creating a checkpoint at output 1.
+fn update_tx(setup: &mut OracleSetup) -> TransactionView {
+    let live_cell = setup.live_time_cell(1_700_000_000_000);
+    let header = header(150, 1_700_000_600_000);
+    setup.context.insert_header(header.clone());
+    let tx = setup
+        .oracle
+        .build_update_tx(&live_cell, &header)
+        .expect("update tx");
+    setup.context.complete_tx(tx)
+}
+
+#[test]
+fn update_with_checkpoint() {
+    let mut setup = setup();
+    let tx = update_tx(&mut setup);
+    assert_eq!(tx.outputs().len(), 2);
+    assert_eq!(tx.outputs_data().get(0), tx.outputs_data().get(1));
+
+    let cycles = setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn checkpoint_mismatch() {
+    let mut setup = setup();
+    let tx = update_tx(&mut setup);
+    let time_info = TimeInfo::from_slice(&tx.outputs_data().get(1).unwrap().raw_data())
+        .unwrap()
+        .as_builder()
+        .timestamp(1_700_000_500_000.into())
+        .build();
+    let mut outputs_data: Vec<Bytes> = tx.outputs_data().into_iter().map(|d| d.unpack()).collect();
+    outputs_data[1] = time_info.as_bytes();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data.into_iter().map(|d| d.pack()).collect())
+        .build();
+
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, CheckpointError::CheckpointMismatch);
+}
+
+#[test]
+fn checkpoint_without_update() {
+    let mut setup = setup();
+    let input_out_point = setup.context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1_000_000_000_000u64.pack())
+            .lock(setup.always_success.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let checkpoint = setup.oracle.checkpoint.clone().unwrap();
+    let tx = setup
+        .oracle
+        .build_create_tx(&input, 0, setup.always_success.clone())
+        .expect("create tx");
+    let time_type = tx.outputs().get(0).unwrap().type_().to_opt().unwrap();
+    let data = tx.outputs_data().get(0).unwrap();
+    let tx = tx
+        .as_advanced_builder()
+        .output(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(setup.always_success.clone())
+                .type_(Some(checkpoint.type_script(&time_type)).pack())
+                .build(),
+        )
+        .output_data(data)
+        .cell_dep(checkpoint.cell_dep)
+        .build();
+    let tx = setup.context.complete_tx(tx);
+
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, CheckpointError::NotAnUpdate);
+}
+
+#[test]
+fn spend_checkpoint() {
+    let mut setup = setup();
+    let tx = update_tx(&mut setup);
+    let checkpoint_out_point = setup.context.create_cell(
+        tx.outputs().get(1).unwrap(),
+        tx.outputs_data().get(1).unwrap().raw_data(),
+    );
+
+    // a later update may not consume the checkpoint
+    let tx = update_tx(&mut setup);
+    let tx = tx
+        .as_advanced_builder()
+        .input(
+            CellInput::new_builder()
+                .previous_output(checkpoint_out_point)
+                .build(),
+        )
+        .build();
+    let tx = setup.context.complete_tx(tx);
+
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, CheckpointError::CheckpointSpent);
+}
@@ -0,0 +1,164 @@
+use crate::assert_script_error;
+use crate::header;
+use crate::OracleSetup;
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
+use time_oracle_error::DeadlineLockError;
+
+const MAX_CYCLES: u64 = 10_000_000;
+const DEADLINE: u64 = 1_700_000_000_000;
+const MINUTE: u64 = 60 * 1000;
+
+fn deadline_lock(setup: &mut OracleSetup, owner: Option<&Script>) -> Script {
+    let mut args = [
+        setup.time_type.calc_script_hash().as_slice(),
+        &DEADLINE.to_le_bytes(),
+    ]
+    .concat();
+    if let Some(owner) = owner {
+        args.extend_from_slice(owner.calc_script_hash().as_slice());
+    }
+    setup
+        .context
+        .build_script(&setup.contract_out_point, Bytes::from(args))
+        .expect("script")
+}
+
+// A transaction spending a cell locked by lock, referencing the time cell at
+// oracle_timestamp and proving current time with a header at proven_timestamp.
+fn spend_tx(
+    setup: &mut OracleSetup,
+    lock: Script,
+    oracle_timestamp: u64,
+    proven_timestamp: u64,
+) -> TransactionView {
+    let input_out_point = setup.context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock)
+            .build(),
+        Bytes::new(),
+    );
+    let header = header(100, proven_timestamp);
+    setup.context.insert_header(header.clone());
+    let time_cell_dep = setup.time_cell_dep(oracle_timestamp);
+    let tx = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .build(),
+        )
+        .output(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(setup.always_success.clone())
+                .build(),
+        )
+        .output_data(Bytes::new().pack())
+        .cell_dep(time_cell_dep)
+        .header_dep(header.hash())
+        .build();
+    setup.context.complete_tx(tx)
+}
+
+#[test]
+fn spend_before_deadline() {
+    let mut setup = OracleSetup::new("deadline-lock");
+    let lock = deadline_lock(&mut setup, None);
+
+    let tx = spend_tx(&mut setup, lock, DEADLINE - MINUTE, DEADLINE - MINUTE);
+    let cycles = setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn spend_after_deadline() {
+    let mut setup = OracleSetup::new("deadline-lock");
+    let lock = deadline_lock(&mut setup, None);
+
+    let tx = spend_tx(&mut setup, lock.clone(), DEADLINE, DEADLINE);
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, DeadlineLockError::DeadlinePassed);
+
+    // the oracle lags behind, but the header proves the deadline passed
+    let tx = spend_tx(&mut setup, lock, DEADLINE - MINUTE, DEADLINE + MINUTE);
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, DeadlineLockError::DeadlinePassed);
+}
+
+#[test]
+fn spend_with_stale_oracle() {
+    let mut setup = OracleSetup::new("deadline-lock");
+    let lock = deadline_lock(&mut setup, None);
+
+    let tx = spend_tx(&mut setup, lock, DEADLINE - 60 * MINUTE, DEADLINE - MINUTE);
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, DeadlineLockError::StaleTimeInfo);
+}
+
+#[test]
+fn spend_without_oracle() {
+    let mut setup = OracleSetup::new("deadline-lock");
+    let lock = deadline_lock(&mut setup, None);
+
+    let tx = spend_tx(
+        &mut setup,
+        lock.clone(),
+        DEADLINE - MINUTE,
+        DEADLINE - MINUTE,
+    );
+    let tx = tx.as_advanced_builder().set_cell_deps(vec![]).build();
+    let tx = setup.context.complete_tx(tx);
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, DeadlineLockError::TimeCellMissing);
+
+    let tx = spend_tx(&mut setup, lock, DEADLINE - MINUTE, DEADLINE - MINUTE);
+    let tx = tx.as_advanced_builder().set_header_deps(vec![]).build();
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, DeadlineLockError::MissingTimeProof);
+}
+
+#[test]
+fn spend_with_owner() {
+    let mut setup = OracleSetup::new("deadline-lock");
+    let owner = setup
+        .always_success
+        .clone()
+        .as_builder()
+        .args(Bytes::from(vec![1]).pack())
+        .build();
+    let lock = deadline_lock(&mut setup, Some(&owner));
+
+    let tx = spend_tx(&mut setup, lock, DEADLINE - MINUTE, DEADLINE - MINUTE);
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, DeadlineLockError::OwnerMissing);
+
+    let owner_out_point = setup.context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(owner)
+            .build(),
+        Bytes::new(),
+    );
+    let tx = tx
+        .as_advanced_builder()
+        .input(
+            CellInput::new_builder()
+                .previous_output(owner_out_point)
+                .build(),
+        )
+        .build();
+    let tx = setup.context.complete_tx(tx);
+    let cycles = setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
@@ -0,0 +1,334 @@
+use crate::assert_script_error;
+use crate::header;
+use crate::OracleSetup;
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{HeaderView, TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
+use ckb_testtool::context::Context;
+use time_oracle_error::LendingError;
+use time_oracle_sdk::{LiveTimeCell, TimeOracle};
+
+const MAX_CYCLES: u64 = 10_000_000;
+const GENESIS_TIMESTAMP: u64 = 1_700_000_000_000;
+const DAY: u64 = 24 * 60 * 60 * 1000;
+const YEAR: u64 = 365 * DAY;
+// 5% per year
+const RATE: u64 = 500;
+const PRINCIPAL: u64 = 1_000 * 100_000_000;
+const POOL: u64 = 100 * 100_000_000;
+
+// Deployed scripts, the live time cell and the header it was updated to.
+struct LendingSetup {
+    context: Context,
+    oracle: TimeOracle,
+    time_cell: LiveTimeCell,
+    header: HeaderView,
+    lending_script: Script,
+    depositor_lock: Script,
+    pool_lock: Script,
+}
+
+fn setup() -> LendingSetup {
+    let mut setup = OracleSetup::new("lending");
+    let time_cell = setup.live_time_cell(GENESIS_TIMESTAMP);
+    let lending_script = setup
+        .context
+        .build_script(
+            &setup.contract_out_point,
+            Bytes::from(
+                [
+                    setup.time_type.calc_script_hash().as_slice(),
+                    &RATE.to_le_bytes(),
+                ]
+                .concat(),
+            ),
+        )
+        .expect("script");
+    let lock_with_args = |args: u8| {
+        setup
+            .always_success
+            .clone()
+            .as_builder()
+            .args(Bytes::from(vec![args]).pack())
+            .build()
+    };
+
+    LendingSetup {
+        depositor_lock: lock_with_args(1),
+        pool_lock: lock_with_args(2),
+        context: setup.context,
+        oracle: setup.oracle,
+        time_cell,
+        header: header(100, GENESIS_TIMESTAMP),
+        lending_script,
+    }
+}
+
+// Updating the time cell to timestamp with a verified update transaction, and
+// replacing the live time cell with its output.
+fn advance_oracle(setup: &mut LendingSetup, timestamp: u64) {
+    let header = header(setup.header.number() + 100, timestamp);
+    setup.context.insert_header(header.clone());
+    let tx = setup
+        .oracle
+        .build_update_tx(&setup.time_cell, &header)
+        .expect("update tx");
+    let tx = setup.context.complete_tx(tx);
+    setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+
+    let output = tx.outputs().get(0).expect("time cell");
+    let data = tx.outputs_data().get(0).expect("time info").raw_data();
+    setup.time_cell = LiveTimeCell {
+        out_point: setup.context.create_cell(output.clone(), data.clone()),
+        output,
+        data,
+        block_hash: None,
+    };
+    setup.header = header;
+}
+
+fn time_cell_dep(setup: &LendingSetup) -> CellDep {
+    CellDep::new_builder()
+        .out_point(setup.time_cell.out_point.clone())
+        .build()
+}
+
+fn input(setup: &mut LendingSetup, output: CellOutput, data: Bytes) -> CellInput {
+    let out_point = setup.context.create_cell(output, data);
+    CellInput::new_builder().previous_output(out_point).build()
+}
+
+// A deposit cell with data committed in the block of header.
+fn committed_deposit(setup: &mut LendingSetup, data: Bytes, header: &HeaderView) -> CellInput {
+    let deposit = deposit_output(setup, PRINCIPAL);
+    let deposit = input(setup, deposit, data);
+    setup.context.insert_header(header.clone());
+    setup
+        .context
+        .link_cell_with_block(deposit.previous_output(), header.hash(), 0);
+    deposit
+}
+
+fn deposit_output(setup: &LendingSetup, capacity: u64) -> CellOutput {
+    CellOutput::new_builder()
+        .capacity(capacity.pack())
+        .lock(setup.depositor_lock.clone())
+        .type_(Some(setup.lending_script.clone()).pack())
+        .build()
+}
+
+fn pool_output(setup: &LendingSetup, capacity: u64) -> CellOutput {
+    CellOutput::new_builder()
+        .capacity(capacity.pack())
+        .lock(setup.pool_lock.clone())
+        .type_(Some(setup.lending_script.clone()).pack())
+        .build()
+}
+
+// A transaction depositing PRINCIPAL at timestamp, proving current time with
+// the header of the latest oracle update.
+fn deposit_tx(setup: &mut LendingSetup, timestamp: u64) -> TransactionView {
+    let funds = CellOutput::new_builder()
+        .capacity(PRINCIPAL.pack())
+        .lock(setup.depositor_lock.clone())
+        .build();
+    let input = input(setup, funds, Bytes::new());
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .output(deposit_output(setup, PRINCIPAL))
+        .output_data(Bytes::from(timestamp.to_le_bytes().to_vec()).pack())
+        .cell_dep(time_cell_dep(setup))
+        .header_dep(setup.header.hash())
+        .build();
+    setup.context.complete_tx(tx)
+}
+
+// A transaction withdrawing deposit committed in the block of committed in one
+// step, paying payout to the depositor and leaving pool_left in the pool.
+fn withdraw_tx(
+    setup: &mut LendingSetup,
+    deposit: CellInput,
+    committed: &HeaderView,
+    payout: u64,
+    pool_left: u64,
+) -> TransactionView {
+    let pool = pool_output(setup, POOL);
+    let pool = input(setup, pool, Bytes::new());
+    let tx = TransactionBuilder::default()
+        .input(deposit)
+        .input(pool)
+        .output(
+            CellOutput::new_builder()
+                .capacity(payout.pack())
+                .lock(setup.depositor_lock.clone())
+                .build(),
+        )
+        .output_data(Bytes::new().pack())
+        .output(pool_output(setup, pool_left))
+        .output_data(Bytes::new().pack())
+        .cell_dep(time_cell_dep(setup))
+        .header_dep(committed.hash())
+        .build();
+    setup.context.complete_tx(tx)
+}
+
+fn interest(elapsed: u64) -> u64 {
+    (PRINCIPAL as u128 * RATE as u128 * elapsed as u128 / (10_000 * YEAR as u128)) as u64
+}
+
+#[test]
+fn deposit_at_oracle_time() {
+    let mut setup = setup();
+    advance_oracle(&mut setup, GENESIS_TIMESTAMP + DAY);
+
+    let tx = deposit_tx(&mut setup, GENESIS_TIMESTAMP + DAY);
+    let cycles = setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+
+    // backdated deposits would earn interest they did not wait for
+    let tx = deposit_tx(&mut setup, GENESIS_TIMESTAMP);
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, LendingError::InvalidDepositTime);
+
+    let tx = deposit_tx(&mut setup, GENESIS_TIMESTAMP + DAY);
+    let tx = tx.as_advanced_builder().set_header_deps(vec![]).build();
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, LendingError::MissingTimeProof);
+
+    // an oracle left behind by keepers cannot timestamp deposits
+    let later = header(setup.header.number() + 100, GENESIS_TIMESTAMP + DAY + DAY);
+    setup.context.insert_header(later.clone());
+    let tx = deposit_tx(&mut setup, GENESIS_TIMESTAMP + DAY);
+    let tx = tx
+        .as_advanced_builder()
+        .set_header_deps(vec![later.hash()])
+        .build();
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, LendingError::StaleTimeInfo);
+}
+
+#[test]
+fn withdraw_accrues_interest() {
+    let mut setup = setup();
+    advance_oracle(&mut setup, GENESIS_TIMESTAMP + DAY);
+    let tx = deposit_tx(&mut setup, GENESIS_TIMESTAMP + DAY);
+    setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    let deposit_data = tx.outputs_data().get(0).expect("deposit").raw_data();
+    let committed = setup.header.clone();
+
+    // withdrawals in a single transaction as the oracle moves forward
+    for elapsed in [30 * DAY, 180 * DAY, YEAR] {
+        advance_oracle(&mut setup, GENESIS_TIMESTAMP + DAY + elapsed);
+        let interest = interest(elapsed);
+        assert!(interest > 0);
+
+        let deposit = committed_deposit(&mut setup, deposit_data.clone(), &committed);
+        let tx = withdraw_tx(
+            &mut setup,
+            deposit.clone(),
+            &committed,
+            PRINCIPAL + interest,
+            POOL - interest,
+        );
+        let cycles = setup
+            .context
+            .verify_tx(&tx, MAX_CYCLES)
+            .expect("pass verification");
+        println!("consume cycles: {}", cycles);
+
+        let tx = withdraw_tx(
+            &mut setup,
+            deposit.clone(),
+            &committed,
+            PRINCIPAL + interest - 1,
+            POOL - interest + 1,
+        );
+        let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+        assert_script_error(err, LendingError::InterestNotPaid);
+
+        let tx = withdraw_tx(
+            &mut setup,
+            deposit,
+            &committed,
+            PRINCIPAL + interest,
+            POOL - interest - 1,
+        );
+        let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+        assert_script_error(err, LendingError::PoolOverdrawn);
+    }
+    assert_eq!(interest(YEAR), PRINCIPAL / 20);
+}
+
+#[test]
+fn deposit_with_old_header_dep() {
+    let mut setup = setup();
+    advance_oracle(&mut setup, GENESIS_TIMESTAMP + DAY);
+    // keepers leave the oracle behind while the chain moves on a day, the
+    // depositor proves current time with the header of the last update and is
+    // stamped a day before the deposit is committed
+    let tx = deposit_tx(&mut setup, GENESIS_TIMESTAMP + DAY);
+    setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    let deposit_data = tx.outputs_data().get(0).expect("deposit").raw_data();
+    let committed = header(setup.header.number() + 50, GENESIS_TIMESTAMP + 2 * DAY);
+    let deposit = committed_deposit(&mut setup, deposit_data, &committed);
+    advance_oracle(&mut setup, GENESIS_TIMESTAMP + 32 * DAY);
+
+    // interest accrues from the committing block only
+    let backdated = interest(31 * DAY);
+    let tx = withdraw_tx(
+        &mut setup,
+        deposit.clone(),
+        &committed,
+        PRINCIPAL + backdated,
+        POOL - backdated,
+    );
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, LendingError::PoolOverdrawn);
+
+    let interest = interest(30 * DAY);
+    let tx = withdraw_tx(
+        &mut setup,
+        deposit.clone(),
+        &committed,
+        PRINCIPAL + interest,
+        POOL - interest,
+    );
+    let cycles = setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+
+    let tx = tx.as_advanced_builder().set_header_deps(vec![]).build();
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, LendingError::DepositHeaderMissing);
+}
+
+#[test]
+fn withdraw_without_time_cell() {
+    let mut setup = setup();
+    let deposit_data = Bytes::from(GENESIS_TIMESTAMP.to_le_bytes().to_vec());
+    let committed = setup.header.clone();
+    let deposit = committed_deposit(&mut setup, deposit_data, &committed);
+    let tx = withdraw_tx(&mut setup, deposit, &committed, PRINCIPAL, POOL);
+    let tx = tx.as_advanced_builder().set_cell_deps(vec![]).build();
+    let tx = setup.context.complete_tx(tx);
+
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, LendingError::TimeCellMissing);
+}
@@ -1 +1,8 @@
+mod checkpoint;
+mod deadline_lock;
+mod lending;
+mod replay;
+mod sdk;
 mod time;
+mod time_lock;
+mod vesting;
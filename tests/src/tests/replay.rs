@@ -0,0 +1,95 @@
+use crate::replay::replay;
+use crate::Loader;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, core::TransactionView, packed::*, prelude::*};
+use ckb_testtool::context::Context;
+use time_oracle_error::Error;
+use time_oracle_sdk::TimeOracle;
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+// Transaction creating a time cell, with the deployed time binary.
+fn create_tx() -> (Context, TransactionView) {
+    let mut context = Context::default();
+    let time_out_point = context.deploy_cell(Loader::default().load_binary("time"));
+    let time_script = context
+        .build_script(&time_out_point, Bytes::new())
+        .expect("script");
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let lock_script = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("script");
+    let oracle = TimeOracle::new(
+        time_script.code_hash(),
+        time_script.hash_type().try_into().expect("hash type"),
+        vec![
+            CellDep::new_builder().out_point(time_out_point).build(),
+            CellDep::new_builder()
+                .out_point(always_success_out_point)
+                .build(),
+        ],
+    );
+
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let tx = oracle
+        .build_create_tx(&CellInput::new(input_out_point, 0), 0, lock_script)
+        .expect("create tx");
+    (context, tx)
+}
+
+#[test]
+fn replay_passing_tx() {
+    let (context, tx) = create_tx();
+    let mock_tx = context.dump_tx(&tx).expect("dump tx");
+
+    let reports = replay(mock_tx, Loader::default().load_binary("time"), MAX_CYCLES).unwrap();
+    let time_report = reports.iter().find(|report| report.is_time).unwrap();
+    assert!(matches!(time_report.result, Ok(cycles) if cycles > 0));
+    assert!(reports.iter().all(|report| report.result.is_ok()));
+}
+
+#[test]
+fn replay_failing_tx() {
+    let (context, tx) = create_tx();
+    // type id committing to another output index
+    let output = tx.output(0).unwrap();
+    let type_script = output.type_().to_opt().unwrap();
+    let mut args: Vec<u8> = type_script.args().raw_data().to_vec();
+    args[..32].copy_from_slice(&[1; 32]);
+    let output = output
+        .as_builder()
+        .type_(
+            Some(
+                type_script
+                    .as_builder()
+                    .args(Bytes::from(args).pack())
+                    .build(),
+            )
+            .pack(),
+        )
+        .build();
+    let tx = tx.as_advanced_builder().set_outputs(vec![output]).build();
+    let mock_tx = context.dump_tx(&tx).expect("dump tx");
+
+    let reports = replay(mock_tx, Loader::default().load_binary("time"), MAX_CYCLES).unwrap();
+    let time_report = reports.iter().find(|report| report.is_time).unwrap();
+    assert_eq!(time_report.exit_code(), Some(Error::TypeIDNotMatch.into()));
+    assert!(time_report.to_string().ends_with(
+        ": exit code 21 TypeIDNotMatch, type id in args does not match the first input"
+    ));
+}
+
+#[test]
+fn replay_without_time_cell_dep() {
+    let (context, tx) = create_tx();
+    let mut mock_tx = context.dump_tx(&tx).expect("dump tx");
+    mock_tx.mock_info.cell_deps.clear();
+
+    assert!(replay(mock_tx, Bytes::new(), MAX_CYCLES).is_err());
+}
@@ -0,0 +1,356 @@
+use crate::assert_script_error;
+use crate::header;
+use crate::Loader;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{bytes::Bytes, core::ScriptHashType, packed::*, prelude::*};
+use ckb_testtool::context::Context;
+use time_oracle_error::Error as ScriptError;
+use time_oracle_reader::{verify_time_proof, Error as ReaderError};
+use time_oracle_sdk::{
+    parse_time_info, parse_time_mmr, Byte32Vec, Error, LiveTimeCell, TimeArgs, TimeInfo,
+    TimeMmrBuilder, TimeOracle, TIME_INFO_VERSION,
+};
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+// Deployed time type script and an always success lock for the time cell.
+fn setup() -> (Context, TimeOracle, Script) {
+    let mut context = Context::default();
+    let time_out_point = context.deploy_cell(Loader::default().load_binary("time"));
+    let time_script = context
+        .build_script(&time_out_point, Bytes::new())
+        .expect("script");
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let lock_script = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("script");
+
+    let oracle = TimeOracle::new(
+        time_script.code_hash(),
+        ScriptHashType::Type,
+        vec![
+            CellDep::new_builder().out_point(time_out_point).build(),
+            CellDep::new_builder()
+                .out_point(always_success_out_point)
+                .build(),
+        ],
+    );
+    (context, oracle, lock_script)
+}
+
+// Creates a live time cell whose type args carry args_extension.
+fn live_time_cell(
+    context: &mut Context,
+    oracle: &TimeOracle,
+    lock_script: Script,
+    args_extension: &[u8],
+) -> LiveTimeCell {
+    let type_script = oracle
+        .type_script(&CellInput::default(), 0, &lock_script)
+        .expect("type script");
+    let args: Bytes = type_script.args().unpack();
+    let type_script = type_script
+        .as_builder()
+        .args(Bytes::from([args.as_ref(), args_extension].concat()).pack())
+        .build();
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(lock_script)
+        .type_(Some(type_script).pack())
+        .build();
+    let data = TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(1_700_000_000_000.into())
+        .block_number(100.into())
+        .build()
+        .as_bytes();
+    let out_point = context.create_cell(output.clone(), data.clone());
+    LiveTimeCell {
+        out_point,
+        output,
+        data,
+        block_hash: None,
+    }
+}
+
+#[test]
+fn sdk_create_tx() {
+    let (mut context, oracle, lock_script) = setup();
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1_000_000_000_000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    let tx = oracle
+        .build_create_tx(&input, 0, lock_script)
+        .expect("create tx");
+    let tx = context.complete_tx(tx);
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn sdk_create_sharded_tx() {
+    let (mut context, oracle, lock_script) = setup();
+    let oracle = oracle.with_time_args(&TimeArgs::new_builder().shard_count(3.into()).build());
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1_000_000_000_000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    let tx = oracle
+        .build_create_tx(&input, 0, lock_script.clone())
+        .expect("create tx");
+    assert_eq!(tx.outputs().len(), 3);
+    assert_eq!(
+        tx.outputs().get(2).unwrap().type_().to_opt(),
+        oracle.shard_type_script(&input, 0, &lock_script, 2).ok()
+    );
+    let tx = context.complete_tx(tx);
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn sdk_create_tx_with_invalid_args_extension() {
+    let (_, mut oracle, lock_script) = setup();
+    oracle.args_extension = Bytes::from(vec![3, 0]);
+
+    assert_eq!(oracle.shard_count(), Err(Error::InvalidArgsExtension));
+    assert_eq!(
+        oracle.build_create_tx(&CellInput::default(), 0, lock_script),
+        Err(Error::InvalidArgsExtension)
+    );
+}
+
+#[test]
+fn sdk_update_tx() {
+    let (mut context, oracle, lock_script) = setup();
+    let live_cell = live_time_cell(&mut context, &oracle, lock_script, &[]);
+    let header = header(150, 1_700_000_600_000);
+    context.insert_header(header.clone());
+
+    let tx = oracle
+        .build_update_tx(&live_cell, &header)
+        .expect("update tx");
+    let tx = context.complete_tx(tx);
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn sdk_update_tx_with_min_interval() {
+    let (mut context, oracle, lock_script) = setup();
+    let extension = [
+        [1].as_slice(),
+        &0u64.to_le_bytes(),
+        &600_000u64.to_le_bytes(),
+    ]
+    .concat();
+    let mut live_cell = live_time_cell(&mut context, &oracle, lock_script, &extension);
+    let header = header(150, 1_700_000_600_000);
+    context.insert_header(header.clone());
+    assert_eq!(
+        oracle.build_update_tx(&live_cell, &header).unwrap_err(),
+        Error::CreatedHeaderMissing
+    );
+
+    let created_header = self::header(100, 1_699_999_000_000);
+    context.insert_header(created_header.clone());
+    context.link_cell_with_block(live_cell.out_point.clone(), created_header.hash(), 0);
+    live_cell.block_hash = Some(created_header.hash());
+    let tx = oracle
+        .build_update_tx(&live_cell, &header)
+        .expect("update tx");
+    let tx = context.complete_tx(tx);
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn sdk_update_tx_with_stale_header() {
+    let (mut context, oracle, lock_script) = setup();
+    let live_cell = live_time_cell(&mut context, &oracle, lock_script, &[]);
+    assert_eq!(
+        oracle
+            .build_update_tx(&live_cell, &header(100, 1_700_000_600_000))
+            .unwrap_err(),
+        Error::TimeInfoRegression
+    );
+}
+
+#[test]
+fn sdk_update_tx_with_keepers() {
+    let (mut context, oracle, lock_script) = setup();
+    let keeper_lock = lock_script
+        .clone()
+        .as_builder()
+        .args(Bytes::from(vec![1]).pack())
+        .build();
+    let keeper_hash: [u8; 32] = keeper_lock.calc_script_hash().unpack();
+    let time_args = TimeArgs::new_builder()
+        .keepers(Byte32Vec::new_builder().push(keeper_hash.into()).build())
+        .build();
+    let oracle = oracle.with_time_args(&time_args);
+    let live_cell = live_time_cell(&mut context, &oracle, lock_script, &[]);
+    let header = header(150, 1_700_000_600_000);
+    context.insert_header(header.clone());
+
+    let tx = oracle
+        .build_update_tx(&live_cell, &header)
+        .expect("update tx");
+    let failing_tx = context.complete_tx(tx.clone());
+    let err = context.verify_tx(&failing_tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ScriptError::KeeperNotAllowed);
+
+    let keeper_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(keeper_lock)
+            .build(),
+        Bytes::new(),
+    );
+    let tx = tx
+        .as_advanced_builder()
+        .input(
+            CellInput::new_builder()
+                .previous_output(keeper_out_point)
+                .build(),
+        )
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn sdk_update_tx_with_mmr() {
+    let (mut context, oracle, lock_script) = setup();
+    let oracle = oracle.with_mmr();
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1_000_000_000_000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let tx = oracle
+        .build_create_tx(&input, 0, lock_script)
+        .expect("create tx");
+    let tx = context.complete_tx(tx);
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+
+    let mut output = tx.outputs().get(0).unwrap();
+    let mut data = tx.outputs_data().get(0).unwrap().raw_data();
+    let mut builder = TimeMmrBuilder::default();
+    assert_eq!(
+        parse_time_mmr(&data).unwrap().map(|mmr| mmr.as_bytes()),
+        Some(builder.time_mmr().as_bytes())
+    );
+    for i in 1..=5 {
+        let live_cell = LiveTimeCell {
+            out_point: context.create_cell(output.clone(), data.clone()),
+            output: output.clone(),
+            data: data.clone(),
+            block_hash: None,
+        };
+        let header = header(100 + i, 1_700_000_000_000 + i * 600_000);
+        context.insert_header(header.clone());
+        let tx = oracle
+            .build_update_tx(&live_cell, &header)
+            .expect("update tx");
+        let tx = context.complete_tx(tx);
+        let cycles = context
+            .verify_tx(&tx, MAX_CYCLES)
+            .expect("pass verification");
+        println!("consume cycles: {}", cycles);
+
+        output = tx.outputs().get(0).unwrap();
+        data = tx.outputs_data().get(0).unwrap().raw_data();
+        builder.push(&parse_time_info(&data).unwrap());
+        assert_eq!(
+            parse_time_mmr(&data).unwrap().map(|mmr| mmr.as_bytes()),
+            Some(builder.time_mmr().as_bytes())
+        );
+    }
+
+    let time_mmr = builder.time_mmr();
+    for leaf_index in 0..5 {
+        let proof = builder.proof(leaf_index).expect("proof");
+        assert_eq!(verify_time_proof(&time_mmr, proof.as_reader()), Ok(()));
+    }
+    assert!(builder.proof(5).is_none());
+
+    let proof = builder.proof(2).unwrap();
+    let entry = proof
+        .entry()
+        .as_builder()
+        .timestamp(1_700_000_000_001.into())
+        .build();
+    let proof = proof.as_builder().entry(entry).build();
+    assert_eq!(
+        verify_time_proof(&time_mmr, proof.as_reader()),
+        Err(ReaderError::InvalidTimeProof)
+    );
+}
+
+#[test]
+fn sdk_update_tx_dropping_mmr() {
+    let (mut context, oracle, lock_script) = setup();
+    let mut live_cell = live_time_cell(&mut context, &oracle, lock_script, &[]);
+    live_cell.data = Bytes::from(
+        [
+            live_cell.data.as_ref(),
+            TimeMmrBuilder::default().time_mmr().as_slice(),
+        ]
+        .concat(),
+    );
+    live_cell.out_point = context.create_cell(live_cell.output.clone(), live_cell.data.clone());
+    let header = header(150, 1_700_000_600_000);
+    context.insert_header(header.clone());
+
+    let tx = oracle
+        .build_update_tx(&live_cell, &header)
+        .expect("update tx");
+    let time_info = parse_time_info(&tx.outputs_data().get(0).unwrap().raw_data()).unwrap();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(vec![time_info.as_bytes().pack()])
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ScriptError::TimeMmrMismatch);
+}
@@ -1,12 +1,47 @@
 use crate::assert_script_error;
 use crate::Loader;
+use crate::{epoch, header};
 use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::ckb_hash::new_blake2b;
-use ckb_testtool::ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*};
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{HeaderView, TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
 use ckb_testtool::context::Context;
+use time_oracle_error::Error;
+use time_oracle_types::{TimeInfo, TIME_INFO_VERSION};
 
 const MAX_CYCLES: u64 = 10_000_000;
 
+fn time_info_data(timestamp: u64, block_number: u64) -> Bytes {
+    TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(timestamp.into())
+        .block_number(block_number.into())
+        .epoch(epoch(block_number).into())
+        .build()
+        .as_bytes()
+}
+
+// TimeInfo of the update following a time cell holding time_info_data.
+fn next_time_info_data(timestamp: u64, block_number: u64) -> Bytes {
+    TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(timestamp.into())
+        .block_number(block_number.into())
+        .sequence(1.into())
+        .epoch(epoch(block_number).into())
+        .build()
+        .as_bytes()
+}
+
+// Absolute since which is satisfied once the median time reaches the given seconds.
+fn timestamp_since(seconds: u64) -> u64 {
+    0x4000_0000_0000_0000 | seconds
+}
+
 #[test]
 fn create_success() {
     // deploy contract
@@ -61,7 +96,7 @@ fn create_success() {
         .type_(Some(type_id_script.clone()).pack())
         .build()];
 
-    let outputs_data = vec![Bytes::new(); 1];
+    let outputs_data = [time_info_data(1_700_000_000_000, 100)];
 
     // build transaction
     let tx = TransactionBuilder::default()
@@ -140,7 +175,7 @@ fn create_on_second_output() {
             .build(),
     ];
 
-    let outputs_data = vec![Bytes::new(); 2];
+    let outputs_data = [Bytes::new(), time_info_data(1_700_000_000_000, 100)];
 
     // build transaction
     let tx = TransactionBuilder::default()
@@ -213,7 +248,7 @@ fn create_fail() {
 
     // run
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
-    assert_script_error(err, 21);
+    assert_script_error(err, Error::TypeIDNotMatch);
 }
 
 #[test]
@@ -271,7 +306,7 @@ fn one_in_one_out_with_wrong_args() {
 
     // run
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
-    assert_script_error(err, 22);
+    assert_script_error(err, Error::ArgsLengthNotEnough);
 }
 
 #[test]
@@ -340,7 +375,7 @@ fn invalid_type_id_lock() {
 
     // run
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
-    assert_script_error(err, 23);
+    assert_script_error(err, Error::InvalidTypeIDLock);
 }
 
 #[test]
@@ -398,10 +433,11 @@ fn udpate_success() {
             .lock(lock_script.clone())
             .type_(Some(type_id_script.clone()).pack())
             .build(),
-        Bytes::new(),
+        time_info_data(1_700_000_000_000, 100),
     );
     let input = CellInput::new_builder()
         .previous_output(input_out_point)
+        .since(timestamp_since(1_700_003_600).pack())
         .build();
 
     let outputs = vec![CellOutput::new_builder()
@@ -410,7 +446,10 @@ fn udpate_success() {
         .type_(Some(type_id_script.clone()).pack())
         .build()];
 
-    let outputs_data = vec![Bytes::new(); 1];
+    let outputs_data = [next_time_info_data(1_700_003_600_000, 400)];
+
+    let header_dep = header(400, 1_700_003_600_000);
+    context.insert_header(header_dep.clone());
 
     // build transaction
     let tx = TransactionBuilder::default()
@@ -419,6 +458,7 @@ fn udpate_success() {
         .outputs_data(outputs_data.pack())
         .cell_dep(lock_script_dep.clone())
         .cell_dep(type_script_dep.clone())
+        .header_dep(header_dep.hash())
         .build();
     let tx = context.complete_tx(tx);
 
@@ -430,7 +470,7 @@ fn udpate_success() {
 }
 
 #[test]
-fn not_allow_destroy() {
+fn update_drain_capacity() {
     // deploy contract
     let mut context = Context::default();
     let contract_bin: Bytes = Loader::default().load_binary("time");
@@ -484,12 +524,101 @@ fn not_allow_destroy() {
             .lock(lock_script.clone())
             .type_(Some(type_id_script.clone()).pack())
             .build(),
+        time_info_data(1_700_000_000_000, 100),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since(timestamp_since(1_700_003_600).pack())
+        .build();
+
+    let outputs = vec![CellOutput::new_builder()
+        .capacity(999u64.pack())
+        .lock(lock_script.clone())
+        .type_(Some(type_id_script.clone()).pack())
+        .build()];
+
+    let outputs_data = [next_time_info_data(1_700_003_600_000, 400)];
+
+    let header_dep = header(400, 1_700_003_600_000);
+    context.insert_header(header_dep.clone());
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(lock_script_dep.clone())
+        .cell_dep(type_script_dep.clone())
+        .header_dep(header_dep.hash())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::CapacityDecreased);
+}
+
+#[test]
+fn not_allow_destroy() {
+    // deploy contract
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time");
+    let type_id_out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder()
+        .out_point(type_id_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let lock_script = context
+        .build_script(&always_success_out_point.clone(), Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare cells
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
         Bytes::new(),
     );
     let input = CellInput::new_builder()
         .previous_output(input_out_point)
         .build();
 
+    let input_hash = {
+        let mut blake2b = new_blake2b();
+        blake2b.update(input.as_slice());
+        blake2b.update(&0u64.to_le_bytes());
+        let mut ret = [0; 32];
+        blake2b.finalize(&mut ret);
+        Bytes::from(ret.to_vec())
+    };
+
+    let lock_script_hash = lock_script.clone().calc_script_hash();
+
+    let type_id_script = context
+        .build_script(
+            &type_id_out_point,
+            Bytes::from([input_hash.iter().as_slice(), lock_script_hash.as_slice()].concat()),
+        )
+        .unwrap();
+
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_id_script.clone()).pack())
+            .build(),
+        time_info_data(1_700_000_000_000, 100),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
     let outputs = vec![CellOutput::new_builder()
         .capacity(1000u64.pack())
         .lock(lock_script.clone())
@@ -509,5 +638,635 @@ fn not_allow_destroy() {
 
     // run
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
-    assert_script_error(err, 20);
+    assert_script_error(err, Error::TimeCellDestroyed);
+}
+
+// Builds a transaction creating a time cell with the given data on the first output.
+fn build_create_tx(data: Bytes) -> (Context, TransactionView) {
+    let mut context = Context::default();
+    let contract_bin: Bytes = Loader::default().load_binary("time");
+    let type_id_out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder()
+        .out_point(type_id_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let lock_script = context
+        .build_script(&always_success_out_point.clone(), Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    let input_hash = {
+        let mut blake2b = new_blake2b();
+        blake2b.update(input.as_slice());
+        blake2b.update(&0u64.to_le_bytes());
+        let mut ret = [0; 32];
+        blake2b.finalize(&mut ret);
+        Bytes::from(ret.to_vec())
+    };
+
+    let lock_script_hash = lock_script.clone().calc_script_hash();
+
+    let type_id_script = context
+        .build_script(
+            &type_id_out_point,
+            Bytes::from([input_hash.iter().as_slice(), lock_script_hash.as_slice()].concat()),
+        )
+        .unwrap();
+    let outputs = vec![CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(lock_script)
+        .type_(Some(type_id_script).pack())
+        .build()];
+
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data([data].pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+    (context, tx)
+}
+
+#[test]
+fn create_with_empty_time_info() {
+    let (context, tx) = build_create_tx(Bytes::new());
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::InvalidTimeInfo);
+}
+
+#[test]
+fn create_with_oversized_time_info() {
+    // data after the TimeInfo is read as a TimeMmr
+    let data = [time_info_data(1_700_000_000_000, 100).as_ref(), &[0]].concat();
+    let (context, tx) = build_create_tx(data.into());
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::InvalidTimeMmr);
+}
+
+#[test]
+fn create_with_unsupported_time_info_version() {
+    let data = TimeInfo::new_builder()
+        .version((TIME_INFO_VERSION + 1).into())
+        .timestamp(1_700_000_000_000u64.into())
+        .build()
+        .as_bytes();
+    let (context, tx) = build_create_tx(data);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::InvalidTimeInfo);
+}
+
+#[test]
+fn create_two_time_cells() {
+    let data = time_info_data(1_700_000_000_000, 100);
+    let (context, tx) = build_create_tx(data.clone());
+    let tx = tx
+        .as_advanced_builder()
+        .output(tx.output(0).unwrap())
+        .output_data(data.pack())
+        .build();
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::MultipleTimeOutputs);
+}
+
+#[test]
+fn update_two_time_cells() {
+    let input_data = time_info_data(1_700_000_000_000, 100);
+    let (mut context, tx) = build_update_tx(
+        input_data.clone(),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+    );
+    let second_out_point = context.create_cell(tx.output(0).unwrap(), input_data);
+    let tx = tx
+        .as_advanced_builder()
+        .input(
+            CellInput::new_builder()
+                .previous_output(second_out_point)
+                .build(),
+        )
+        .build();
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::MultipleTimeInputs);
+}
+
+// Builds a transaction updating an existing time cell from input_data to output_data,
+// the time cell input carries the given since and header is added to header_deps.
+fn build_update_tx(
+    input_data: Bytes,
+    output_data: Bytes,
+    since: u64,
+    header: HeaderView,
+) -> (Context, TransactionView) {
+    build_update_tx_with_args(&[], None, input_data, output_data, since, header, 0)
+}
+
+// Same as build_update_tx, but appends args_extension to the type script args.
+// When created_header is given, the time cell input is committed in that block
+// and its header is added to header_deps as well, otherwise it is committed in
+// a block missing from header_deps. A non-zero fee adds an extra input of that
+// capacity which is burnt by the transaction.
+fn build_update_tx_with_args(
+    args_extension: &[u8],
+    created_header: Option<HeaderView>,
+    input_data: Bytes,
+    output_data: Bytes,
+    since: u64,
+    header: HeaderView,
+    fee: u64,
+) -> (Context, TransactionView) {
+    let mut context = Context::default();
+    context.insert_header(header.clone());
+    let contract_bin: Bytes = Loader::default().load_binary("time");
+    let type_id_out_point = context.deploy_cell(contract_bin);
+    let type_script_dep = CellDep::new_builder()
+        .out_point(type_id_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let lock_script = context
+        .build_script(&always_success_out_point.clone(), Default::default())
+        .expect("script");
+    let lock_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    let lock_script_hash = lock_script.clone().calc_script_hash();
+    let type_id_script = context
+        .build_script(
+            &type_id_out_point,
+            Bytes::from(
+                [
+                    [1; 32].as_slice(),
+                    lock_script_hash.as_slice(),
+                    args_extension,
+                ]
+                .concat(),
+            ),
+        )
+        .unwrap();
+
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock_script.clone())
+            .type_(Some(type_id_script.clone()).pack())
+            .build(),
+        input_data,
+    );
+    // live cells are always committed in a block, created_header only decides
+    // whether its header is in header_deps
+    let mut header_deps = vec![header.hash()];
+    let committed_header = created_header
+        .clone()
+        .unwrap_or_else(|| self::header(100, 1_699_999_000_000));
+    context.insert_header(committed_header.clone());
+    context.link_cell_with_block(input_out_point.clone(), committed_header.hash(), 0);
+    if let Some(created_header) = created_header {
+        header_deps.push(created_header.hash());
+    }
+    let mut inputs = vec![CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since(since.pack())
+        .build()];
+    if fee > 0 {
+        let fee_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(fee.pack())
+                .lock(lock_script.clone())
+                .build(),
+            Bytes::new(),
+        );
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(fee_out_point)
+                .build(),
+        );
+    }
+    let outputs = vec![CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(lock_script)
+        .type_(Some(type_id_script).pack())
+        .build()];
+
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data([output_data].pack())
+        .cell_dep(lock_script_dep)
+        .cell_dep(type_script_dep)
+        .header_deps(header_deps)
+        .build();
+    let tx = context.complete_tx(tx);
+    (context, tx)
+}
+
+#[test]
+fn update_with_higher_timestamp() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+    );
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn update_with_equal_timestamp() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_000_000, 150),
+        timestamp_since(1_700_000_000),
+        header(150, 1_700_000_000_000),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::TimeInfoRegression);
+}
+
+#[test]
+fn update_with_lower_timestamp() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_699_999_400_000, 150),
+        timestamp_since(1_699_999_400),
+        header(150, 1_699_999_400_000),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::TimeInfoRegression);
+}
+
+#[test]
+fn update_with_lower_block_number() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 99),
+        timestamp_since(1_700_000_600),
+        header(99, 1_700_000_600_000),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::TimeInfoRegression);
+}
+
+#[test]
+fn update_with_repeated_sequence() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::InvalidSequence);
+}
+
+// Time cells of the legacy testnet deployment were created with empty data,
+// their first update sets the genesis TimeInfo.
+#[test]
+fn update_legacy_time_cell_with_empty_data() {
+    let (context, tx) = build_update_tx(
+        Bytes::new(),
+        time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+    );
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn update_within_since_tolerance() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_000),
+        header(150, 1_700_000_600_000),
+    );
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn update_ahead_of_since() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_001, 150),
+        timestamp_since(1_700_000_000),
+        header(150, 1_700_000_600_001),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::TimestampInFuture);
+}
+
+#[test]
+fn update_without_since() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        0,
+        header(150, 1_700_000_600_000),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::InvalidSince);
+}
+
+#[test]
+fn update_with_relative_since() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        0xc000_0000_0000_0000 | 600,
+        header(150, 1_700_000_600_000),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::InvalidSince);
+}
+
+#[test]
+fn update_without_matching_header_dep() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(149, 1_700_000_600_000),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::HeaderDepMissing);
+}
+
+#[test]
+fn update_before_header_dep() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_001),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::TimestampOutOfHeaderWindow);
+}
+
+#[test]
+fn update_at_end_of_header_dep_window() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_000_000),
+    );
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn update_after_header_dep_window() {
+    let (context, tx) = build_update_tx(
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_699_999_999_999),
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::TimestampOutOfHeaderWindow);
+}
+
+// Args extension carrying the header dep window in milliseconds.
+fn header_dep_window_args(header_dep_window: u64) -> Vec<u8> {
+    [
+        [1].as_slice(),
+        &header_dep_window.to_le_bytes(),
+        &0u64.to_le_bytes(),
+    ]
+    .concat()
+}
+
+// Args extension carrying the minimum update interval in milliseconds.
+fn min_update_interval_args(min_update_interval: u64) -> Vec<u8> {
+    [
+        [1].as_slice(),
+        &0u64.to_le_bytes(),
+        &min_update_interval.to_le_bytes(),
+    ]
+    .concat()
+}
+
+#[test]
+fn update_within_configured_header_dep_window() {
+    let (context, tx) = build_update_tx_with_args(
+        &header_dep_window_args(20 * 60 * 1000),
+        None,
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_699_999_400_000),
+        0,
+    );
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn update_after_configured_header_dep_window() {
+    let (context, tx) = build_update_tx_with_args(
+        &header_dep_window_args(60 * 1000),
+        None,
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_539_999),
+        0,
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::TimestampOutOfHeaderWindow);
+}
+
+#[test]
+fn update_after_min_interval() {
+    let (context, tx) = build_update_tx_with_args(
+        &min_update_interval_args(600_000),
+        Some(header(100, 1_700_000_000_000)),
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+        0,
+    );
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn update_before_min_interval() {
+    let (context, tx) = build_update_tx_with_args(
+        &min_update_interval_args(600_000),
+        Some(header(100, 1_700_000_000_000)),
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_599_000, 150),
+        timestamp_since(1_700_000_599),
+        header(150, 1_700_000_599_000),
+        0,
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::UpdateTooFrequent);
+}
+
+#[test]
+fn update_with_min_interval_without_created_header() {
+    let (context, tx) = build_update_tx_with_args(
+        &min_update_interval_args(600_000),
+        None,
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+        0,
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::CreatedHeaderMissing);
+}
+
+#[test]
+fn update_with_unsupported_args_version() {
+    let (context, tx) = build_update_tx_with_args(
+        &[4; 17],
+        None,
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+        0,
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::InvalidArgsVersion);
+}
+
+// Args extension carrying the minimum fee in shannons.
+fn min_fee_args(min_fee: u64) -> Vec<u8> {
+    [
+        [2].as_slice(),
+        &0u64.to_le_bytes(),
+        &0u64.to_le_bytes(),
+        &min_fee.to_le_bytes(),
+    ]
+    .concat()
+}
+
+#[test]
+fn update_with_fee_at_threshold() {
+    let (context, tx) = build_update_tx_with_args(
+        &min_fee_args(100_000),
+        None,
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+        100_000,
+    );
+
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn update_with_fee_below_threshold() {
+    let (context, tx) = build_update_tx_with_args(
+        &min_fee_args(100_000),
+        None,
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+        99_999,
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::FeeTooLow);
+}
+
+#[test]
+fn update_without_fee() {
+    let (context, tx) = build_update_tx_with_args(
+        &min_fee_args(100_000),
+        None,
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+        0,
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::FeeTooLow);
+}
+
+#[test]
+fn update_with_invalid_args_extension_length() {
+    let (context, tx) = build_update_tx_with_args(
+        &[2; 17],
+        None,
+        time_info_data(1_700_000_000_000, 100),
+        next_time_info_data(1_700_000_600_000, 150),
+        timestamp_since(1_700_000_600),
+        header(150, 1_700_000_600_000),
+        0,
+    );
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, Error::InvalidArgsExtensionLength);
 }
@@ -0,0 +1,207 @@
+use crate::assert_script_error;
+use crate::Loader;
+use crate::{epoch, header};
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
+use ckb_testtool::context::Context;
+use time_oracle_error::TimeLockError;
+use time_oracle_types::{TimeInfo, TIME_INFO_VERSION};
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+fn time_info_data(timestamp: u64, block_number: u64) -> Bytes {
+    TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(timestamp.into())
+        .block_number(block_number.into())
+        .epoch(epoch(block_number).into())
+        .build()
+        .as_bytes()
+}
+
+// TimeInfo of the update following a time cell holding time_info_data.
+fn next_time_info_data(timestamp: u64, block_number: u64) -> Bytes {
+    TimeInfo::new_builder()
+        .version(TIME_INFO_VERSION.into())
+        .timestamp(timestamp.into())
+        .block_number(block_number.into())
+        .sequence(1.into())
+        .epoch(epoch(block_number).into())
+        .build()
+        .as_bytes()
+}
+
+// Deployed scripts and the time cell input locked by time lock.
+struct TimeLockSetup {
+    context: Context,
+    time_lock_script: Script,
+    time_type_script: Script,
+    input: CellInput,
+}
+
+fn setup() -> TimeLockSetup {
+    let mut context = Context::default();
+    let time_out_point = context.deploy_cell(Loader::default().load_binary("time"));
+    let time_lock_out_point = context.deploy_cell(Loader::default().load_binary("time-lock"));
+
+    // time lock only accepts inputs typed by the time contract
+    let time_script = context
+        .build_script(&time_out_point, Bytes::new())
+        .expect("script");
+    let time_lock_script = context
+        .build_script(
+            &time_lock_out_point,
+            Bytes::from(
+                [
+                    time_script.code_hash().as_slice(),
+                    time_script.hash_type().as_slice(),
+                ]
+                .concat(),
+            ),
+        )
+        .expect("script");
+    let time_type_script = context
+        .build_script(
+            &time_out_point,
+            Bytes::from(
+                [
+                    [1; 32].as_slice(),
+                    time_lock_script.calc_script_hash().as_slice(),
+                ]
+                .concat(),
+            ),
+        )
+        .expect("script");
+
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(time_lock_script.clone())
+            .type_(Some(time_type_script.clone()).pack())
+            .build(),
+        time_info_data(1_700_000_000_000, 100),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .since((0x4000_0000_0000_0000u64 | 1_700_000_600).pack())
+        .build();
+
+    TimeLockSetup {
+        context,
+        time_lock_script,
+        time_type_script,
+        input,
+    }
+}
+
+fn build_tx(setup: &mut TimeLockSetup, output: CellOutput, data: Bytes) -> TransactionView {
+    let header = header(150, 1_700_000_600_000);
+    setup.context.insert_header(header.clone());
+
+    let tx = TransactionBuilder::default()
+        .input(setup.input.clone())
+        .output(output)
+        .output_data(data.pack())
+        .header_dep(header.hash())
+        .build();
+    setup.context.complete_tx(tx)
+}
+
+#[test]
+fn update_time_cell() {
+    let mut setup = setup();
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(setup.time_lock_script.clone())
+        .type_(Some(setup.time_type_script.clone()).pack())
+        .build();
+    let tx = build_tx(
+        &mut setup,
+        output,
+        next_time_info_data(1_700_000_600_000, 150),
+    );
+
+    let cycles = setup
+        .context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn spend_without_time_output() {
+    let mut setup = setup();
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(setup.time_lock_script.clone())
+        .build();
+    let tx = build_tx(&mut setup, output, Bytes::new());
+
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, TimeLockError::TimeOutputMissing);
+}
+
+#[test]
+fn time_output_with_other_lock() {
+    let mut setup = setup();
+    let always_success_out_point = setup.context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let lock_script = setup
+        .context
+        .build_script(&always_success_out_point, Default::default())
+        .expect("script");
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(lock_script)
+        .type_(Some(setup.time_type_script.clone()).pack())
+        .build();
+    let tx = build_tx(
+        &mut setup,
+        output,
+        next_time_info_data(1_700_000_600_000, 150),
+    );
+
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, TimeLockError::TimeOutputMissing);
+}
+
+#[test]
+fn time_output_with_invalid_time_info() {
+    let mut setup = setup();
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(setup.time_lock_script.clone())
+        .type_(Some(setup.time_type_script.clone()).pack())
+        .build();
+    let tx = build_tx(&mut setup, output, Bytes::new());
+
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, TimeLockError::InvalidTimeInfo);
+}
+
+#[test]
+fn spend_cell_without_time_type() {
+    let mut setup = setup();
+    let input_out_point = setup.context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(setup.time_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    setup.input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(setup.time_lock_script.clone())
+        .build();
+    let tx = build_tx(&mut setup, output, Bytes::new());
+
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, TimeLockError::TimeTypeMissing);
+}
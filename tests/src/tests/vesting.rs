@@ -0,0 +1,201 @@
+use crate::assert_script_error;
+use crate::OracleSetup;
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
+use time_oracle_error::VestingError;
+
+const MAX_CYCLES: u64 = 10_000_000;
+const DAY: u64 = 24 * 60 * 60 * 1000;
+const START: u64 = 1_700_000_000_000;
+const CLIFF: u64 = START + 90 * DAY;
+const END: u64 = START + 360 * DAY;
+const TOTAL: u64 = 3_600 * 100_000_000;
+
+// Vesting type reading the time cells of setup.
+fn vesting_type(setup: &mut OracleSetup) -> Script {
+    let args = Bytes::from(setup.time_type.calc_script_hash().as_slice().to_vec());
+    setup
+        .context
+        .build_script(&setup.contract_out_point, args)
+        .expect("script")
+}
+
+fn schedule(start: u64, cliff: u64, end: u64, total: u64) -> Bytes {
+    Bytes::from(
+        [start, cliff, end, total]
+            .iter()
+            .flat_map(|field| field.to_le_bytes())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn vesting_output(setup: &mut OracleSetup, capacity: u64) -> CellOutput {
+    CellOutput::new_builder()
+        .capacity(capacity.pack())
+        .lock(setup.always_success.clone())
+        .type_(Some(vesting_type(setup)).pack())
+        .build()
+}
+
+// A transaction creating a vesting cell of capacity with schedule data.
+fn create_tx(setup: &mut OracleSetup, capacity: u64, data: Bytes) -> TransactionView {
+    let input_out_point = setup.context.create_cell(
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(setup.always_success.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let tx = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .build(),
+        )
+        .output(vesting_output(setup, capacity))
+        .output_data(data.pack())
+        .build();
+    setup.context.complete_tx(tx)
+}
+
+// A transaction withdrawing from a vesting cell of capacity at the oracle time
+// now, leaving remaining in the vesting cell or destroying it when None.
+fn withdraw_tx(
+    setup: &mut OracleSetup,
+    capacity: u64,
+    now: u64,
+    remaining: Option<u64>,
+) -> TransactionView {
+    let data = schedule(START, CLIFF, END, TOTAL);
+    let input = vesting_output(setup, capacity);
+    let input_out_point = setup.context.create_cell(input, data.clone());
+    let time_cell_dep = setup.time_cell_dep(now);
+    let withdrawn = capacity - remaining.unwrap_or(0);
+    let mut builder = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .build(),
+        )
+        .output(
+            CellOutput::new_builder()
+                .capacity(withdrawn.pack())
+                .lock(setup.always_success.clone())
+                .build(),
+        )
+        .output_data(Bytes::new().pack())
+        .cell_dep(time_cell_dep);
+    if let Some(remaining) = remaining {
+        builder = builder
+            .output(vesting_output(setup, remaining))
+            .output_data(data.pack());
+    }
+    setup.context.complete_tx(builder.build())
+}
+
+fn verify(setup: &mut OracleSetup, tx: &TransactionView) {
+    let cycles = setup
+        .context
+        .verify_tx(tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn create_vesting() {
+    let mut setup = OracleSetup::new("vesting");
+    let tx = create_tx(&mut setup, TOTAL, schedule(START, CLIFF, END, TOTAL));
+    verify(&mut setup, &tx);
+
+    let tx = create_tx(&mut setup, TOTAL - 1, schedule(START, CLIFF, END, TOTAL));
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::InvalidSchedule);
+
+    let tx = create_tx(&mut setup, TOTAL, schedule(START, END + 1, END, TOTAL));
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::InvalidSchedule);
+}
+
+#[test]
+fn release_before_cliff() {
+    let mut setup = OracleSetup::new("vesting");
+
+    // a quarter has accrued linearly, but nothing is released until the cliff
+    let tx = withdraw_tx(&mut setup, TOTAL, CLIFF - 1, Some(TOTAL - 1));
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::UnvestedWithdrawn);
+
+    let tx = withdraw_tx(&mut setup, TOTAL, CLIFF, Some(TOTAL * 3 / 4));
+    verify(&mut setup, &tx);
+}
+
+#[test]
+fn partial_release() {
+    let mut setup = OracleSetup::new("vesting");
+
+    let half = START + 180 * DAY;
+    let tx = withdraw_tx(&mut setup, TOTAL, half, Some(TOTAL / 2));
+    verify(&mut setup, &tx);
+
+    let tx = withdraw_tx(&mut setup, TOTAL, half, Some(TOTAL / 2 - 1));
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::UnvestedWithdrawn);
+
+    // a later withdrawal from the remaining cell only takes the newly vested
+    let tx = withdraw_tx(&mut setup, TOTAL / 2, START + 270 * DAY, Some(TOTAL / 4));
+    verify(&mut setup, &tx);
+
+    let tx = withdraw_tx(&mut setup, TOTAL / 2, half, Some(TOTAL / 2 - 1));
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::UnvestedWithdrawn);
+}
+
+#[test]
+fn full_release() {
+    let mut setup = OracleSetup::new("vesting");
+
+    let tx = withdraw_tx(&mut setup, TOTAL, END, None);
+    verify(&mut setup, &tx);
+
+    let tx = withdraw_tx(&mut setup, TOTAL, END - 1, None);
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::UnvestedWithdrawn);
+}
+
+#[test]
+fn withdraw_with_invalid_cells() {
+    let mut setup = OracleSetup::new("vesting");
+
+    let tx = withdraw_tx(&mut setup, TOTAL, END, Some(TOTAL / 2));
+    let tx = tx.as_advanced_builder().set_cell_deps(vec![]).build();
+    let tx = setup.context.complete_tx(tx);
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::TimeCellMissing);
+
+    let tx = withdraw_tx(&mut setup, TOTAL, END, Some(TOTAL / 2));
+    let mut outputs_data: Vec<Bytes> = tx.outputs_data().into_iter().map(|d| d.unpack()).collect();
+    outputs_data[1] = schedule(START, CLIFF, END + DAY, TOTAL);
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data.into_iter().map(|d| d.pack()).collect())
+        .build();
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::ScheduleChanged);
+
+    let tx = withdraw_tx(&mut setup, TOTAL, END, Some(TOTAL / 2));
+    let other_lock = setup
+        .always_success
+        .clone()
+        .as_builder()
+        .args(Bytes::from(vec![1]).pack())
+        .build();
+    let mut outputs: Vec<CellOutput> = tx.outputs().into_iter().collect();
+    outputs[1] = outputs[1].clone().as_builder().lock(other_lock).build();
+    let tx = tx.as_advanced_builder().set_outputs(outputs).build();
+    let err = setup.context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, VestingError::LockChanged);
+}